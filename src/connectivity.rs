@@ -0,0 +1,215 @@
+//! This module checks that a `Grid` forms a single connected puzzle.
+//!
+//! Two words are connected when they share a cell of the board. The connected
+//! components of a grid are the groups of words that can reach each other through such
+//! shared cells. A well-formed crossword has exactly one component; every other
+//! component is reported as an `Island`.
+
+use std::fmt::Display;
+
+use crate::owned::OwnedGrid;
+use crate::word::Position;
+use crate::Grid;
+
+/// `Island` is a group of words that is not connected to the main part of the puzzle.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Island {
+    /// The indices of the words of the island in `Grid.words`, in ascending order.
+    pub words: Vec<usize>,
+    /// The answers of the words of the island, in the order of `words`.
+    pub answers: Vec<String>,
+    /// The top-left start cell of the words of the island, in reading order.
+    pub position: Position,
+}
+
+impl Display for Island {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at ({}, {})",
+            self.answers.join(", "),
+            self.position.x,
+            self.position.y
+        )
+    }
+}
+
+/// Returns the root of `index` in the union-find forest `parents`, compressing the path.
+fn find_root(parents: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    let mut current = index;
+    while parents[current] != root {
+        let next = parents[current];
+        parents[current] = root;
+        current = next;
+    }
+    root
+}
+
+impl Grid<'_> {
+    /// Returns the connected components of the grid.
+    ///
+    /// Words are joined into a component when they share a cell of the board. Each
+    /// component is a list of indices into `Grid.words` in ascending order. The
+    /// components are sorted from the largest to the smallest, and components of the same
+    /// size by the index of their first word, so the main part of the puzzle comes first.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Vec<usize>>` with one entry per component. It is empty if the grid has no words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction}};
+    ///
+    /// let mut cat = Word::value("", 'C', "AT").unwrap().direction(Direction::Horizontal);
+    /// cat.update_position();
+    /// let mut ace = Word::value("", 'A', "CE").unwrap().position(1, 0).direction(Direction::Vertical);
+    /// ace.update_position();
+    /// let mut dog = Word::value("", 'D', "OG").unwrap().position(3, 3).direction(Direction::Horizontal);
+    /// dog.update_position();
+    /// let board = vec![
+    ///     vec!['C', 'A', 'T', ' ', ' ', ' '],
+    ///     vec![' ', 'C', ' ', ' ', ' ', ' '],
+    ///     vec![' ', 'E', ' ', ' ', ' ', ' '],
+    ///     vec![' ', ' ', ' ', 'D', 'O', 'G'],
+    /// ];
+    /// let grid = Grid::from_parts(vec![cat, ace, dog], board).unwrap();
+    ///
+    /// assert_eq!(grid.components(), vec![vec![0, 1], vec![2]]);
+    /// ```
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        let mut parents: Vec<usize> = (0..self.words.len()).collect();
+        let width = self.board.first().map_or(0, |row| row.len());
+        let mut owners: Vec<Vec<Option<usize>>> = vec![vec![None; width]; self.board.len()];
+
+        for (index, word) in self.words.iter().enumerate() {
+            for pos in word.positions() {
+                let Some(owner) = owners.get_mut(pos.y).and_then(|row| row.get_mut(pos.x)) else {
+                    continue;
+                };
+                match *owner {
+                    Some(other) => {
+                        let (a, b) = (
+                            find_root(&mut parents, other),
+                            find_root(&mut parents, index),
+                        );
+                        parents[a.max(b)] = a.min(b);
+                    }
+                    None => *owner = Some(index),
+                }
+            }
+        }
+
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        let mut component_of_root: Vec<Option<usize>> = vec![None; self.words.len()];
+        for index in 0..self.words.len() {
+            let root = find_root(&mut parents, index);
+            match component_of_root[root] {
+                Some(component) => components[component].push(index),
+                None => {
+                    component_of_root[root] = Some(components.len());
+                    components.push(vec![index]);
+                }
+            }
+        }
+
+        components.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+        components
+    }
+
+    /// Returns `true` if every word of the grid is connected to every other word through
+    /// shared cells. A grid without words is considered connected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate, Grid};
+    ///
+    /// let grid = generate(&["LOREM", "IPSUM", "DOLOR", "SIT", "AMET"]).unwrap().unwrap();
+    /// assert!(grid.is_connected());
+    /// assert!(Grid::new().is_connected());
+    /// ```
+    pub fn is_connected(&self) -> bool {
+        self.components().len() <= 1
+    }
+
+    /// Returns the islands of the grid: every connected component except the largest one.
+    ///
+    /// This is useful to check grids loaded from files, which may have been edited by
+    /// hand. A connected grid has no islands.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<Island>`, ordered like `Grid::components`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction, Position}};
+    ///
+    /// let mut cat = Word::value("", 'C', "AT").unwrap().direction(Direction::Horizontal);
+    /// cat.update_position();
+    /// let mut ace = Word::value("", 'A', "CE").unwrap().position(1, 0).direction(Direction::Vertical);
+    /// ace.update_position();
+    /// let mut dog = Word::value("", 'D', "OG").unwrap().position(3, 3).direction(Direction::Horizontal);
+    /// dog.update_position();
+    /// let board = vec![
+    ///     vec!['C', 'A', 'T', ' ', ' ', ' '],
+    ///     vec![' ', 'C', ' ', ' ', ' ', ' '],
+    ///     vec![' ', 'E', ' ', ' ', ' ', ' '],
+    ///     vec![' ', ' ', ' ', 'D', 'O', 'G'],
+    /// ];
+    /// let grid = Grid::from_parts(vec![cat, ace, dog], board).unwrap();
+    ///
+    /// let islands = grid.islands();
+    /// assert_eq!(islands.len(), 1);
+    /// assert_eq!(islands[0].answers, vec!["DOG"]);
+    /// assert_eq!(islands[0].position, Position { x: 3, y: 3 });
+    /// assert_eq!(islands[0].to_string(), "DOG at (3, 3)");
+    /// ```
+    pub fn islands(&self) -> Vec<Island> {
+        self.components()
+            .into_iter()
+            .skip(1)
+            .map(|words| {
+                let position = words
+                    .iter()
+                    .map(|index| self.words[*index].origin)
+                    .min_by_key(|origin| (origin.y, origin.x))
+                    .unwrap_or_default();
+                let answers = words
+                    .iter()
+                    .map(|index| self.words[*index].segment.full_word_str())
+                    .collect();
+                Island {
+                    words,
+                    answers,
+                    position,
+                }
+            })
+            .collect()
+    }
+}
+
+impl OwnedGrid {
+    /// Returns the connected components of the grid (see `Grid::components`).
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        self.as_grid().components()
+    }
+
+    /// Returns `true` if the grid is a single connected component (see `Grid::is_connected`).
+    pub fn is_connected(&self) -> bool {
+        self.as_grid().is_connected()
+    }
+
+    /// Returns the islands of the grid (see `Grid::islands`).
+    pub fn islands(&self) -> Vec<Island> {
+        self.as_grid().islands()
+    }
+}
@@ -0,0 +1,383 @@
+//! This module defines `Dictionary`, an indexed word list for building puzzles.
+//!
+//! A dictionary is loaded once and indexed by word length, by the letter at each position,
+//! by the sorted letters of each word and by the letters each word contains. This answers
+//! the questions asked over and over while building a puzzle, such as "which words match
+//! `A?P?E`", without scanning the whole list. Query results are ordered from the highest
+//! score to the lowest, and words of equal score keep the order they were loaded in.
+
+use std::collections::HashMap;
+
+use crate::error::FormatError;
+use crate::normalize::Normalizer;
+
+/// `DictionaryEntry` is a word of a `Dictionary` together with its score.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DictionaryEntry {
+    /// The word.
+    pub word: String,
+    /// The score of the word. Higher scores mark better words; the default is `0`.
+    pub score: i32,
+}
+
+impl DictionaryEntry {
+    /// Creates a new `DictionaryEntry`.
+    pub fn new(word: impl Into<String>, score: i32) -> Self {
+        Self {
+            word: word.into(),
+            score,
+        }
+    }
+}
+
+/// `Dictionary` is a word list indexed for pattern, anagram and letter queries.
+#[derive(Debug, Clone, Default)]
+pub struct Dictionary {
+    /// The entries, ordered from the highest score to the lowest.
+    entries: Vec<DictionaryEntry>,
+    /// The letters of every entry, in the order of `entries`.
+    letters: Vec<Vec<char>>,
+    /// The index of every word in `entries`.
+    by_word: HashMap<String, usize>,
+    /// The entries of each length, in ascending order.
+    by_length: HashMap<usize, Vec<usize>>,
+    /// The entries of each length having a letter at a position, in ascending order.
+    by_letter_at: HashMap<(usize, usize, char), Vec<usize>>,
+    /// The entries made of each sorted multiset of letters, in ascending order.
+    by_anagram: HashMap<Vec<char>, Vec<usize>>,
+    /// The entries containing each letter at least once, in ascending order.
+    by_letter: HashMap<char, Vec<usize>>,
+}
+
+/// Returns the letters of `word` in sorted order, the key shared by all its anagrams.
+fn anagram_key(word: &str) -> Vec<char> {
+    let mut key: Vec<char> = word.chars().collect();
+    key.sort_unstable();
+    key
+}
+
+impl Dictionary {
+    /// Creates a new, empty `Dictionary`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a `Dictionary` from entries.
+    ///
+    /// Words are trimmed and empty words are skipped. A word given more than once is kept
+    /// once, with its highest score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::dictionary::{Dictionary, DictionaryEntry};
+    ///
+    /// let dictionary = Dictionary::from_entries([
+    ///     DictionaryEntry::new("APPLE", 10),
+    ///     DictionaryEntry::new("AMPLE", 40),
+    ///     DictionaryEntry::new("APPLE", 20),
+    /// ]);
+    ///
+    /// assert_eq!(dictionary.len(), 2);
+    /// assert_eq!(dictionary.score("APPLE"), Some(20));
+    /// assert_eq!(dictionary.of_length(5), vec!["AMPLE", "APPLE"]);
+    /// ```
+    pub fn from_entries(entries: impl IntoIterator<Item = DictionaryEntry>) -> Self {
+        let mut unique: Vec<DictionaryEntry> = Vec::new();
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for entry in entries {
+            let word = entry.word.trim();
+            if word.is_empty() {
+                continue;
+            }
+            match seen.get(word) {
+                Some(index) => unique[*index].score = unique[*index].score.max(entry.score),
+                None => {
+                    seen.insert(word.to_string(), unique.len());
+                    unique.push(DictionaryEntry::new(word, entry.score));
+                }
+            }
+        }
+        unique.sort_by_key(|entry| std::cmp::Reverse(entry.score));
+
+        let mut dictionary = Self::new();
+        for (index, entry) in unique.iter().enumerate() {
+            let letters: Vec<char> = entry.word.chars().collect();
+            dictionary.by_word.insert(entry.word.clone(), index);
+            dictionary
+                .by_length
+                .entry(letters.len())
+                .or_default()
+                .push(index);
+            for (position, letter) in letters.iter().enumerate() {
+                dictionary
+                    .by_letter_at
+                    .entry((letters.len(), position, *letter))
+                    .or_default()
+                    .push(index);
+                let containing = dictionary.by_letter.entry(*letter).or_default();
+                if containing.last() != Some(&index) {
+                    containing.push(index);
+                }
+            }
+            dictionary
+                .by_anagram
+                .entry(anagram_key(&entry.word))
+                .or_default()
+                .push(index);
+            dictionary.letters.push(letters);
+        }
+        dictionary.entries = unique;
+        dictionary
+    }
+
+    /// Builds a `Dictionary` from words, each with a score of `0`. The words are used as
+    /// given, without normalization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::dictionary::Dictionary;
+    ///
+    /// let dictionary = Dictionary::from_words(&["RUST", "TRUST", "RUST"]);
+    /// assert_eq!(dictionary.len(), 2);
+    /// assert!(dictionary.contains("TRUST"));
+    /// ```
+    pub fn from_words(words: &[&str]) -> Self {
+        Self::from_entries(words.iter().map(|word| DictionaryEntry::new(*word, 0)))
+    }
+
+    /// Parses a word list with the default `Normalizer` (see `Dictionary::parse_with`).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FormatError::InvalidData` if a score is not a whole number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::dictionary::Dictionary;
+    ///
+    /// let dictionary = Dictionary::parse("# fruit\napple;50\nmango\nice cream;30\n").unwrap();
+    /// assert_eq!(dictionary.len(), 3);
+    /// assert_eq!(dictionary.score("APPLE"), Some(50));
+    /// assert_eq!(dictionary.score("MANGO"), Some(0));
+    /// assert!(dictionary.contains("ICECREAM"));
+    ///
+    /// assert!(Dictionary::parse("apple;lots").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, FormatError> {
+        Self::parse_with(text, &Normalizer::new())
+    }
+
+    /// Parses a word list, one word per line with an optional `;score` column.
+    ///
+    /// Empty lines and lines starting with `#` are skipped. Every word is passed through
+    /// `normalizer`, and words that are empty afterwards are skipped. Words without a score
+    /// get a score of `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The word list.
+    /// * `normalizer` - The `Normalizer` applied to every word.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Dictionary)` holding the words of the list.
+    /// - `Err(FormatError)` if a line cannot be read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FormatError::InvalidData` if a score is not a whole number.
+    pub fn parse_with(text: &str, normalizer: &Normalizer) -> Result<Self, FormatError> {
+        let mut entries = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (word, score) = match line.rsplit_once(';') {
+                Some((word, score)) => {
+                    let score = score.trim().parse().map_err(|_| {
+                        FormatError::InvalidData(format!(
+                            "line {}: `{}` is not a valid score",
+                            number + 1,
+                            score.trim()
+                        ))
+                    })?;
+                    (word, score)
+                }
+                None => (line, 0),
+            };
+            entries.push(DictionaryEntry::new(normalizer.normalize(word), score));
+        }
+        Ok(Self::from_entries(entries))
+    }
+
+    /// Returns the number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the dictionary holds no words.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entries of the dictionary, ordered from the highest score to the lowest.
+    pub fn entries(&self) -> &[DictionaryEntry] {
+        &self.entries
+    }
+
+    /// Returns `true` if the dictionary holds `word`.
+    pub fn contains(&self, word: &str) -> bool {
+        self.by_word.contains_key(word)
+    }
+
+    /// Returns the score of `word`, or `None` if the dictionary does not hold it.
+    pub fn score(&self, word: &str) -> Option<i32> {
+        self.by_word
+            .get(word)
+            .map(|index| self.entries[*index].score)
+    }
+
+    /// Returns the words made of the entries at `indices`.
+    fn words(&self, indices: &[usize]) -> Vec<&str> {
+        indices
+            .iter()
+            .map(|index| self.entries[*index].word.as_str())
+            .collect()
+    }
+
+    /// Returns the words with `length` letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::dictionary::Dictionary;
+    ///
+    /// let dictionary = Dictionary::from_words(&["SIT", "AMET", "LOREM", "IPSUM"]);
+    /// assert_eq!(dictionary.of_length(5), vec!["LOREM", "IPSUM"]);
+    /// assert!(dictionary.of_length(7).is_empty());
+    /// ```
+    pub fn of_length(&self, length: usize) -> Vec<&str> {
+        self.by_length
+            .get(&length)
+            .map_or_else(Vec::new, |indices| self.words(indices))
+    }
+
+    /// Returns the words with `length` letters that have `letter` at the character
+    /// index `index`, counted from `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::dictionary::Dictionary;
+    ///
+    /// let dictionary = Dictionary::from_words(&["CARGO", "HERON", "BURST", "CAR"]);
+    ///
+    /// // Five-letter words with an R as their third letter.
+    /// assert_eq!(dictionary.with_letter_at(5, 2, 'R'), vec!["CARGO", "HERON", "BURST"]);
+    /// ```
+    pub fn with_letter_at(&self, length: usize, index: usize, letter: char) -> Vec<&str> {
+        self.by_letter_at
+            .get(&(length, index, letter))
+            .map_or_else(Vec::new, |indices| self.words(indices))
+    }
+
+    /// Returns the indices of the entries that fit `pattern`, where `None` stands for any
+    /// letter. The smallest index list of the fixed letters is scanned, and the other
+    /// letters are checked directly.
+    pub(crate) fn pattern_indices(&self, pattern: &[Option<char>]) -> Vec<usize> {
+        let length = pattern.len();
+        let shortest = pattern
+            .iter()
+            .enumerate()
+            .filter_map(|(index, letter)| {
+                letter.map(|letter| {
+                    self.by_letter_at
+                        .get(&(length, index, letter))
+                        .map_or(&[][..], Vec::as_slice)
+                })
+            })
+            .min_by_key(|indices| indices.len());
+        let candidates = match shortest {
+            Some(indices) => indices,
+            None => self.by_length.get(&length).map_or(&[][..], Vec::as_slice),
+        };
+
+        candidates
+            .iter()
+            .copied()
+            .filter(|index| {
+                self.letters[*index]
+                    .iter()
+                    .zip(pattern.iter())
+                    .all(|(letter, expected)| expected.is_none_or(|expected| expected == *letter))
+            })
+            .collect()
+    }
+
+    /// Returns the letters of the entry at `index`.
+    pub(crate) fn letters(&self, index: usize) -> &[char] {
+        &self.letters[index]
+    }
+
+    /// Returns the words that fit a wildcard pattern, in which `?` and `.` stand for any
+    /// single letter and every other character for itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::dictionary::Dictionary;
+    ///
+    /// let dictionary = Dictionary::from_words(&["APPLE", "AMPLE", "ANGLE", "APPLES", "ABODE"]);
+    /// assert_eq!(dictionary.matching("A?P?E"), vec!["APPLE", "AMPLE"]);
+    /// assert_eq!(dictionary.matching("?????").len(), 4);
+    /// ```
+    pub fn matching(&self, pattern: &str) -> Vec<&str> {
+        let pattern: Vec<Option<char>> = pattern
+            .chars()
+            .map(|ch| (ch != '?' && ch != '.').then_some(ch))
+            .collect();
+        self.words(&self.pattern_indices(&pattern))
+    }
+
+    /// Returns the words made of exactly the letters of `letters`, in any order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::dictionary::Dictionary;
+    ///
+    /// let dictionary = Dictionary::from_words(&["LISTEN", "SILENT", "TINSEL", "LINTEL"]);
+    /// assert_eq!(dictionary.anagrams("ENLIST"), vec!["LISTEN", "SILENT", "TINSEL"]);
+    /// ```
+    pub fn anagrams(&self, letters: &str) -> Vec<&str> {
+        self.by_anagram
+            .get(&anagram_key(letters))
+            .map_or_else(Vec::new, |indices| self.words(indices))
+    }
+
+    /// Returns the words that contain `letter` at least once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::dictionary::Dictionary;
+    ///
+    /// let dictionary = Dictionary::from_words(&["RUST", "TEST", "CODE", "ZIP"]);
+    /// assert_eq!(dictionary.containing('T'), vec!["RUST", "TEST"]);
+    /// ```
+    pub fn containing(&self, letter: char) -> Vec<&str> {
+        self.by_letter
+            .get(&letter)
+            .map_or_else(Vec::new, |indices| self.words(indices))
+    }
+
+    /// Returns the number of words that contain `letter` at least once.
+    pub fn count_containing(&self, letter: char) -> usize {
+        self.by_letter.get(&letter).map_or(0, Vec::len)
+    }
+}
@@ -4,6 +4,8 @@
 
 use std::fmt;
 
+use crate::word::{Direction, Position};
+
 /// `WordError` represents specific errors that can occur when creating, validating, or manipulating a `Word`.
 /// These errors typically arise from invalid input or attempts to create words that do not conform to expected rules.
 #[derive(Debug)]
@@ -12,6 +14,10 @@ pub enum WordError {
     EmptyOrWhitespaceSegment,
     /// Indicates that a word segment contains lowercase characters, which are not allowed.
     LowercaseCharactersInSegment,
+    /// Indicates that a word segment contains whitespace, which marks empty cells on the board.
+    WhitespaceInSegment,
+    /// Indicates that a word was split at a character index beyond its last character.
+    CrossingIndexOutOfRange(usize),
 }
 
 /// Implements the `Display` trait for `WordError`, allowing errors to be formatted as user-friendly strings.
@@ -24,6 +30,12 @@ impl fmt::Display for WordError {
             WordError::LowercaseCharactersInSegment => {
                 write!(f, "Segment cannot contain lowercase characters.")
             }
+            WordError::WhitespaceInSegment => {
+                write!(f, "Segment cannot contain whitespace.")
+            }
+            WordError::CrossingIndexOutOfRange(index) => {
+                write!(f, "Crossing index {index} is outside of the word.")
+            }
         }
     }
 }
@@ -39,6 +51,66 @@ pub enum GridError {
     InvalidDirection(String),
     /// Wraps a `WordError` that occurred during a grid operation, providing more context.
     WordError(WordError),
+    /// Indicates that the board is empty or its rows do not all have the same length.
+    MalformedBoard(String),
+    /// Indicates that a word extends beyond the edges of the board.
+    OutOfBounds {
+        /// The answer of the word.
+        answer: String,
+        /// The first cell of the word that lies outside the board.
+        position: Position,
+    },
+    /// Indicates that a cell of the board does not hold the letter a word expects there.
+    LetterMismatch {
+        /// The answer of the word.
+        answer: String,
+        /// The cell holding the wrong letter.
+        position: Position,
+        /// The letter the word expects.
+        expected: char,
+        /// The letter found on the board.
+        found: char,
+    },
+    /// Indicates that a cell of the board holds a letter that belongs to no word.
+    UncoveredLetter {
+        /// The cell holding the letter.
+        position: Position,
+        /// The letter found on the board.
+        letter: char,
+    },
+    /// Indicates that a row or column of the board holds a run of two or more letters
+    /// that is not a placed word.
+    UnintendedWord {
+        /// The letters of the run.
+        letters: String,
+        /// The first cell of the run.
+        position: Position,
+        /// The direction of the run.
+        direction: Direction,
+    },
+    /// Indicates that two words running in the same direction share a cell.
+    OverlappingWords {
+        /// The answer of the word placed first.
+        first: String,
+        /// The answer of the word placed second.
+        second: String,
+        /// The first cell shared by both words.
+        position: Position,
+        /// The direction of both words.
+        direction: Direction,
+    },
+    /// Indicates that two anchor words need different letters in the same cell.
+    ConflictingAnchors {
+        /// The answer of the anchor given first.
+        first: String,
+        /// The answer of the anchor given second.
+        second: String,
+        /// The cell both anchors claim.
+        position: Position,
+    },
+    /// Indicates that anchor words were given without both a maximum width and height, so
+    /// the board could grow before them and move them.
+    UnboundedAnchors,
 }
 
 /// Implements the `Display` trait for `GridError`, allowing errors to be formatted as user-friendly strings.
@@ -47,6 +119,58 @@ impl fmt::Display for GridError {
         match self {
             GridError::InvalidDirection(msg) => write!(f, "Invalid direction: {msg}"),
             GridError::WordError(e) => write!(f, "Word error: {e}"),
+            GridError::MalformedBoard(msg) => write!(f, "Malformed board: {msg}"),
+            GridError::OutOfBounds { answer, position } => write!(
+                f,
+                "Word {answer} extends outside the board at ({}, {}).",
+                position.x, position.y
+            ),
+            GridError::LetterMismatch {
+                answer,
+                position,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Word {answer} expects '{expected}' at ({}, {}) but the board has '{found}'.",
+                position.x, position.y
+            ),
+            GridError::UncoveredLetter { position, letter } => write!(
+                f,
+                "Letter '{letter}' at ({}, {}) does not belong to any word.",
+                position.x, position.y
+            ),
+            GridError::UnintendedWord {
+                letters,
+                position,
+                direction,
+            } => write!(
+                f,
+                "The {direction} run {letters} at ({}, {}) is not a placed word.",
+                position.x, position.y
+            ),
+            GridError::OverlappingWords {
+                first,
+                second,
+                position,
+                direction,
+            } => write!(
+                f,
+                "The {direction} words {first} and {second} overlap at ({}, {}).",
+                position.x, position.y
+            ),
+            GridError::ConflictingAnchors {
+                first,
+                second,
+                position,
+            } => write!(
+                f,
+                "The anchors {first} and {second} need different letters at ({}, {}).",
+                position.x, position.y
+            ),
+            GridError::UnboundedAnchors => {
+                write!(f, "Anchors need a maximum board width and height.")
+            }
         }
     }
 }
@@ -62,6 +186,76 @@ impl From<WordError> for GridError {
     }
 }
 
+/// `FormatError` represents errors that can occur when writing a `Grid` to, or reading one
+/// from, a puzzle file format such as Across Lite `.puz`.
+#[derive(Debug)]
+pub enum FormatError {
+    /// Indicates that a character cannot be represented in the target format.
+    UnsupportedCharacter(char),
+    /// Indicates that the board is larger than the format allows.
+    GridTooLarge {
+        /// The number of columns of the board.
+        width: usize,
+        /// The number of rows of the board.
+        height: usize,
+    },
+    /// Indicates that the puzzle has more clues than the format allows.
+    TooManyClues(usize),
+    /// Indicates that a run of two or more letters on the board is not a placed word,
+    /// so it has no clue.
+    UncluedRun {
+        /// The first cell of the run.
+        position: Position,
+        /// The direction of the run.
+        direction: Direction,
+    },
+    /// Indicates that the input is truncated or otherwise malformed.
+    InvalidData(String),
+    /// Indicates that a checksum stored in the input does not match its contents.
+    ChecksumMismatch(String),
+    /// Wraps a `GridError` raised while rebuilding the grid from the input.
+    GridError(GridError),
+}
+
+/// Implements the `Display` trait for `FormatError`, allowing errors to be formatted as user-friendly strings.
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FormatError::UnsupportedCharacter(ch) => {
+                write!(f, "Character '{ch}' is not supported by the format.")
+            }
+            FormatError::GridTooLarge { width, height } => {
+                write!(f, "A {width}x{height} board is too large for the format.")
+            }
+            FormatError::TooManyClues(count) => {
+                write!(f, "{count} clues are too many for the format.")
+            }
+            FormatError::UncluedRun {
+                position,
+                direction,
+            } => write!(
+                f,
+                "The {direction} run of letters at ({}, {}) is not a placed word.",
+                position.x, position.y
+            ),
+            FormatError::InvalidData(msg) => write!(f, "Invalid data: {msg}"),
+            FormatError::ChecksumMismatch(msg) => write!(f, "Checksum mismatch: {msg}"),
+            FormatError::GridError(e) => write!(f, "Grid error: {e}"),
+        }
+    }
+}
+
+/// Implements the `Error` trait for `FormatError`, providing a common interface for error handling.
+impl std::error::Error for FormatError {}
+
+/// Implements conversion from `GridError` to `FormatError`.
+/// This allows `GridError`s to be easily wrapped within `FormatError`s.
+impl From<GridError> for FormatError {
+    fn from(err: GridError) -> Self {
+        FormatError::GridError(err)
+    }
+}
+
 /// `Error` represents general application errors, encompassing `WordError` and `GridError`,
 /// as well as custom error messages.
 #[derive(Debug)]
@@ -70,6 +264,8 @@ pub enum Error {
     WordError(WordError),
     /// Wraps a `GridError` that occurred within the application.
     GridError(GridError),
+    /// Wraps a `FormatError` that occurred within the application.
+    FormatError(FormatError),
     /// Represents a custom error message, useful for general application-level failures.
     Custom(String),
 }
@@ -80,6 +276,7 @@ impl fmt::Display for Error {
         match self {
             Error::WordError(e) => write!(f, "Word error: {e}"),
             Error::GridError(e) => write!(f, "Grid error: {e}"),
+            Error::FormatError(e) => write!(f, "Format error: {e}"),
             Error::Custom(msg) => write!(f, "Application error: {msg}"),
         }
     }
@@ -104,3 +301,11 @@ impl From<GridError> for Error {
     }
 }
 
+/// Implements conversion from `FormatError` to `Error`.
+/// This allows `FormatError`s to be easily wrapped within the top-level `Error` type.
+impl From<FormatError> for Error {
+    fn from(err: FormatError) -> Self {
+        Error::FormatError(err)
+    }
+}
+
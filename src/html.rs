@@ -0,0 +1,449 @@
+//! This module writes a `Grid` as a single self-contained HTML file that can be played in
+//! a browser, offline. The page embeds its own CSS and JavaScript and loads nothing else.
+//!
+//! The solution is not stored as plain text: every letter is XOR-ed with a pseudo-random
+//! key stream seeded from the puzzle, and the player decodes it when checking or revealing
+//! answers. This keeps the answers from being read off the page source at a glance, but it
+//! is obfuscation, not encryption.
+
+use std::fmt::Write;
+
+use crate::metadata::Metadata;
+use crate::numbering::Clue;
+use crate::owned::OwnedGrid;
+use crate::svg::escape_xml;
+use crate::Grid;
+
+/// The page template. `__TITLE__`, `__HEADING__` and `__DATA__` are replaced when writing.
+const TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>__TITLE__</title>
+<style>
+body { font-family: sans-serif; margin: 1.5em; color: #111; }
+h1 { margin: 0 0 .2em; font-size: 1.6em; }
+.meta { color: #555; margin: 0 0 1em; }
+.layout { display: flex; flex-wrap: wrap; gap: 2em; align-items: flex-start; }
+.grid { display: grid; gap: 0; border: 2px solid #111; width: max-content; }
+.cell { position: relative; width: 2.2em; height: 2.2em; box-sizing: border-box; border: 1px solid #999; background: #fff; }
+.cell.block { background: #222; border-color: #222; }
+.cell.word { background: #dbeafe; }
+.cell.active { background: #fde68a; }
+.cell input { width: 100%; height: 100%; border: 0; padding: .35em 0 0; box-sizing: border-box; background: transparent; text-align: center; font-size: 1.1em; text-transform: uppercase; caret-color: transparent; outline: none; }
+.cell .num { position: absolute; top: 1px; left: 2px; font-size: .55em; color: #333; pointer-events: none; }
+.cell.wrong input { color: #dc2626; }
+.cell.revealed input { color: #2563eb; }
+.current { min-height: 1.4em; margin: 0 0 .6em; padding: .3em .5em; background: #dbeafe; }
+.controls { margin: .8em 0; display: flex; flex-wrap: wrap; gap: .4em; }
+.status { font-weight: bold; min-height: 1.2em; }
+.clues { display: flex; flex-wrap: wrap; gap: 2em; }
+.clues h2 { font-size: 1.1em; margin: 0 0 .4em; }
+.clues ol { list-style: none; padding: 0; margin: 0; max-width: 22em; }
+.clues li { padding: .15em .3em; cursor: pointer; }
+.clues li.active { background: #dbeafe; }
+</style>
+</head>
+<body>
+__HEADING__
+<div class="layout">
+<div>
+<div class="current" id="current"></div>
+<div class="grid" id="grid"></div>
+<div class="controls">
+<button type="button" data-action="check-word">Check word</button>
+<button type="button" data-action="check-all">Check puzzle</button>
+<button type="button" data-action="reveal-word">Reveal word</button>
+<button type="button" data-action="reveal-all">Reveal puzzle</button>
+<button type="button" data-action="clear">Clear</button>
+</div>
+<div class="status" id="status"></div>
+</div>
+<div class="clues">
+<div><h2>Across</h2><ol id="across"></ol></div>
+<div><h2>Down</h2><ol id="down"></ol></div>
+</div>
+</div>
+<script>
+(function () {
+  "use strict";
+  var data = __DATA__;
+  var W = data.width, H = data.height;
+
+  var solution = (function () {
+    var x = data.seed >>> 0, out = [];
+    for (var i = 0; i < data.cells.length; i++) {
+      x ^= x << 13; x >>>= 0;
+      x ^= x >>> 17;
+      x ^= x << 5; x >>>= 0;
+      var code = data.cells[i] ^ (x & 0xffff);
+      out.push(code === 0 ? null : String.fromCodePoint(code));
+    }
+    return out;
+  })();
+
+  var gridEl = document.getElementById("grid");
+  var statusEl = document.getElementById("status");
+  var currentEl = document.getElementById("current");
+  gridEl.style.gridTemplateColumns = "repeat(" + W + ", auto)";
+
+  var cells = [], inputs = [], owners = [];
+  for (var i = 0; i < W * H; i++) {
+    var cell = document.createElement("div");
+    cell.className = "cell";
+    owners.push({});
+    if (solution[i] === null) {
+      cell.className += " block";
+      inputs.push(null);
+    } else {
+      var input = document.createElement("input");
+      input.setAttribute("maxlength", "2");
+      input.setAttribute("autocomplete", "off");
+      input.setAttribute("aria-label", "row " + (Math.floor(i / W) + 1) + ", column " + (i % W + 1));
+      input.dataset.index = i;
+      cell.appendChild(input);
+      inputs.push(input);
+    }
+    cells.push(cell);
+    gridEl.appendChild(cell);
+  }
+  data.numbers.forEach(function (n) {
+    var span = document.createElement("span");
+    span.className = "num";
+    span.textContent = n[0];
+    cells[n[2] * W + n[1]].appendChild(span);
+  });
+
+  var clues = data.clues.map(function (c, index) {
+    var list = [];
+    for (var k = 0; k < c.length; k++) {
+      var cx = c.x + (c.dir === "across" ? k : 0);
+      var cy = c.y + (c.dir === "down" ? k : 0);
+      list.push(cy * W + cx);
+      owners[cy * W + cx][c.dir] = index;
+    }
+    var li = document.createElement("li");
+    li.textContent = c.number + ". " + c.text + " (" + c.length + ")";
+    li.addEventListener("click", function () { select(list[0], c.dir); });
+    document.getElementById(c.dir).appendChild(li);
+    return { number: c.number, dir: c.dir, text: c.text, cells: list, item: li };
+  });
+
+  var cur = -1, dir = "across";
+
+  function wordOf(index, direction) {
+    var owner = owners[index][direction];
+    return owner === undefined ? null : clues[owner];
+  }
+
+  function highlight() {
+    cells.forEach(function (cell) { cell.classList.remove("word", "active"); });
+    clues.forEach(function (clue) { clue.item.classList.remove("active"); });
+    currentEl.textContent = "";
+    if (cur < 0) return;
+    var word = wordOf(cur, dir);
+    if (word) {
+      word.cells.forEach(function (index) { cells[index].classList.add("word"); });
+      word.item.classList.add("active");
+      currentEl.textContent = word.number + " " + word.dir + ": " + word.text;
+    }
+    cells[cur].classList.add("active");
+  }
+
+  function select(index, direction) {
+    if (index < 0 || index >= W * H || !inputs[index]) return;
+    if (direction) dir = direction;
+    if (!wordOf(index, dir)) dir = dir === "across" ? "down" : "across";
+    cur = index;
+    inputs[index].focus();
+    highlight();
+  }
+
+  function step(delta) {
+    var word = wordOf(cur, dir);
+    if (!word) return;
+    var position = word.cells.indexOf(cur) + delta;
+    if (position >= 0 && position < word.cells.length) select(word.cells[position]);
+  }
+
+  function move(dx, dy) {
+    var x = cur % W, y = Math.floor(cur / W);
+    for (x += dx, y += dy; x >= 0 && x < W && y >= 0 && y < H; x += dx, y += dy) {
+      if (inputs[y * W + x]) { select(y * W + x, dx !== 0 ? "across" : "down"); return; }
+    }
+  }
+
+  function nextClue(delta) {
+    var word = wordOf(cur, dir) || clues[0];
+    var index = (clues.indexOf(word) + delta + clues.length) % clues.length;
+    select(clues[index].cells[0], clues[index].dir);
+  }
+
+  function setCell(index, value) {
+    inputs[index].value = value;
+    cells[index].classList.remove("wrong", "revealed");
+    statusEl.textContent = "";
+  }
+
+  inputs.forEach(function (input, index) {
+    if (!input) return;
+    input.addEventListener("mousedown", function (event) {
+      if (index === cur && document.activeElement === input) {
+        event.preventDefault();
+        select(index, dir === "across" ? "down" : "across");
+      }
+    });
+    input.addEventListener("focus", function () { if (cur !== index) select(index); });
+    input.addEventListener("input", function () {
+      var chars = Array.from(input.value.toUpperCase().trim());
+      setCell(index, chars.length ? chars[chars.length - 1] : "");
+      if (chars.length) step(1);
+    });
+    input.addEventListener("keydown", function (event) {
+      switch (event.key) {
+        case "ArrowRight": move(1, 0); break;
+        case "ArrowLeft": move(-1, 0); break;
+        case "ArrowDown": move(0, 1); break;
+        case "ArrowUp": move(0, -1); break;
+        case "Tab": nextClue(event.shiftKey ? -1 : 1); break;
+        case " ": select(cur, dir === "across" ? "down" : "across"); break;
+        case "Backspace":
+          if (input.value) { setCell(index, ""); } else { step(-1); if (cur !== index) setCell(cur, ""); }
+          break;
+        case "Delete": setCell(index, ""); break;
+        default:
+          if (Array.from(event.key).length !== 1 || !event.key.trim() || event.ctrlKey || event.metaKey || event.altKey) return;
+          setCell(index, event.key.toUpperCase());
+          step(1);
+      }
+      event.preventDefault();
+    });
+  });
+
+  function check(list) {
+    var wrong = 0, empty = 0;
+    list.forEach(function (index) {
+      var value = inputs[index].value;
+      if (!value) { empty++; return; }
+      if (value !== solution[index]) { wrong++; cells[index].classList.add("wrong"); }
+    });
+    return { wrong: wrong, empty: empty };
+  }
+
+  function reveal(list) {
+    list.forEach(function (index) {
+      if (inputs[index].value !== solution[index]) {
+        inputs[index].value = solution[index];
+        cells[index].classList.remove("wrong");
+        cells[index].classList.add("revealed");
+      }
+    });
+  }
+
+  function allCells() {
+    return inputs.map(function (input, index) { return input ? index : -1; })
+      .filter(function (index) { return index >= 0; });
+  }
+
+  function currentCells() {
+    var word = cur >= 0 ? wordOf(cur, dir) : null;
+    return word ? word.cells : [];
+  }
+
+  document.querySelectorAll("button[data-action]").forEach(function (button) {
+    button.addEventListener("click", function () {
+      var action = button.dataset.action;
+      if (action === "check-word" || action === "check-all") {
+        var result = check(action === "check-word" ? currentCells() : allCells());
+        if (action === "check-all" && result.wrong === 0 && result.empty === 0) {
+          statusEl.textContent = "Solved!";
+        } else {
+          statusEl.textContent = result.wrong + " wrong, " + result.empty + " empty";
+        }
+      } else if (action === "reveal-word") {
+        reveal(currentCells());
+      } else if (action === "reveal-all") {
+        reveal(allCells());
+      } else if (action === "clear") {
+        allCells().forEach(function (index) { setCell(index, ""); });
+      }
+      if (cur >= 0) inputs[cur].focus();
+    });
+  });
+
+  if (clues.length) select(clues[0].cells[0], clues[0].dir);
+})();
+</script>
+</body>
+</html>
+"#;
+
+/// Encodes `text` as a JavaScript string literal that is also safe inside a `<script>`
+/// element.
+fn js_string(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len() + 2);
+    encoded.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '<' | '>' | '&' | '\u{2028}' | '\u{2029}' => {
+                let _ = write!(encoded, "\\u{:04x}", ch as u32);
+            }
+            ch if ch.is_control() => {
+                let _ = write!(encoded, "\\u{:04x}", ch as u32);
+            }
+            _ => encoded.push(ch),
+        }
+    }
+    encoded.push('"');
+    encoded
+}
+
+/// Advances a xorshift32 key stream, as done by the player script.
+fn next_key(state: &mut u32) -> u32 {
+    *state ^= *state << 13;
+    *state ^= *state >> 17;
+    *state ^= *state << 5;
+    *state
+}
+
+impl Grid<'_> {
+    /// Writes the grid as a self-contained, interactive HTML player.
+    ///
+    /// The page shows the title, author, copyright and notes from `metadata`, the blank
+    /// grid and the across and down clues. Solvers type into the cells; the cursor follows
+    /// the current word, arrow keys move between cells, space or a second click switches
+    /// between across and down, and tab jumps to the next clue. The current word and its
+    /// clue are highlighted. Buttons check or reveal the current word or the whole puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The title, author, copyright and notes shown on the page.
+    ///
+    /// # Returns
+    ///
+    /// A `String` holding a complete HTML document with its CSS and JavaScript inlined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate_with_clues, metadata::Metadata, PuzzleEntry};
+    ///
+    /// let entries = [
+    ///     PuzzleEntry::new("LOREM", "Placeholder text opener"),
+    ///     PuzzleEntry::new("IPSUM", "Placeholder text, part two"),
+    ///     PuzzleEntry::new("DOLOR", "Pain, in Latin"),
+    /// ];
+    /// let grid = generate_with_clues(&entries).unwrap().unwrap();
+    ///
+    /// let html = grid.to_html(&Metadata::new().title("Lorem <ipsum>"));
+    /// assert!(html.starts_with("<!DOCTYPE html>"));
+    /// assert!(html.contains("<title>Lorem &lt;ipsum&gt;</title>"));
+    /// assert!(html.contains("Pain, in Latin"));
+    /// // The answers are not stored as plain text.
+    /// assert!(!html.contains("LOREM"));
+    /// assert!(!html.contains("DOLOR"));
+    /// ```
+    pub fn to_html(&self, metadata: &Metadata) -> String {
+        let height = self.board.len();
+        let width = self.board.first().map_or(0, |row| row.len());
+
+        let mut seed = 0x9E37_79B9u32;
+        for ch in self.board.iter().flatten() {
+            seed = seed.rotate_left(5) ^ (*ch as u32);
+        }
+        seed = seed.max(1);
+
+        let mut key = seed;
+        let cells: Vec<String> = self
+            .board
+            .iter()
+            .flatten()
+            .map(|ch| {
+                let code = if *ch == ' ' { 0 } else { *ch as u32 };
+                (code ^ (next_key(&mut key) & 0xFFFF)).to_string()
+            })
+            .collect();
+
+        let numbers: Vec<String> = self
+            .numbers()
+            .into_iter()
+            .map(|(number, position)| format!("[{number},{},{}]", position.x, position.y))
+            .collect();
+
+        let clues = self.clues();
+        let clue_data = |clue: &Clue, direction: &str| {
+            format!(
+                "{{\"number\":{},\"dir\":\"{direction}\",\"x\":{},\"y\":{},\"length\":{},\"text\":{}}}",
+                clue.number,
+                clue.position.x,
+                clue.position.y,
+                clue.length,
+                js_string(&clue.clue)
+            )
+        };
+        let clue_list: Vec<String> = clues
+            .across
+            .iter()
+            .map(|clue| clue_data(clue, "across"))
+            .chain(clues.down.iter().map(|clue| clue_data(clue, "down")))
+            .collect();
+
+        let data = format!(
+            "{{\"width\":{width},\"height\":{height},\"seed\":{seed},\"cells\":[{}],\"numbers\":[{}],\"clues\":[{}]}}",
+            cells.join(","),
+            numbers.join(","),
+            clue_list.join(",")
+        );
+
+        let title = if metadata.title.is_empty() {
+            "Crossword".to_string()
+        } else {
+            escape_xml(&metadata.title)
+        };
+        let mut heading = format!("<h1>{title}</h1>\n");
+        let byline: Vec<String> = [&metadata.author, &metadata.copyright]
+            .into_iter()
+            .filter(|text| !text.is_empty())
+            .map(|text| escape_xml(text))
+            .collect();
+        if !byline.is_empty() {
+            let _ = writeln!(
+                heading,
+                "<p class=\"meta\">{}</p>",
+                byline.join(" &middot; ")
+            );
+        }
+        if !metadata.notes.is_empty() {
+            let _ = writeln!(heading, "<p>{}</p>", escape_xml(&metadata.notes));
+        }
+
+        // The markers appear once each, in this order. Splitting instead of replacing keeps
+        // markers that happen to appear in the inserted text intact.
+        let mut html = String::with_capacity(TEMPLATE.len() + data.len() + heading.len());
+        let mut rest = TEMPLATE;
+        for (marker, value) in [
+            ("__TITLE__", &title),
+            ("__HEADING__\n", &heading),
+            ("__DATA__", &data),
+        ] {
+            if let Some((before, after)) = rest.split_once(marker) {
+                html.push_str(before);
+                html.push_str(value);
+                rest = after;
+            }
+        }
+        html.push_str(rest);
+        html
+    }
+}
+
+impl OwnedGrid {
+    /// Writes the grid as a self-contained, interactive HTML player (see `Grid::to_html`).
+    pub fn to_html(&self, metadata: &Metadata) -> String {
+        self.as_grid().to_html(metadata)
+    }
+}
@@ -0,0 +1,288 @@
+//! This module reads and writes puzzles in the ipuz format, the open JSON crossword format
+//! accepted by many web solvers (see <http://ipuz.org>).
+//!
+//! An ipuz crossword describes the board twice: `puzzle` holds the empty grid with its clue
+//! numbers and `"#"` blocks, and `solution` holds the letters. The clues are listed under
+//! `clues.Across` and `clues.Down` as `[number, clue]` pairs.
+//!
+//! This module requires the `serde` feature to be enabled.
+
+use serde_json::{json, Map, Value};
+
+use crate::error::FormatError;
+use crate::metadata::Metadata;
+use crate::owned::OwnedGrid;
+use crate::word::Direction;
+use crate::Grid;
+
+/// The ipuz version written to the `version` field.
+const VERSION: &str = "http://ipuz.org/v2";
+/// The puzzle kind written to the `kind` field.
+const KIND: &str = "http://ipuz.org/crossword#1";
+/// The cell value used for blocks.
+const BLOCK: &str = "#";
+
+impl Grid<'_> {
+    /// Writes the grid as an ipuz crossword.
+    ///
+    /// Empty cells become `"#"` blocks. Every run of two or more letters on the board must
+    /// be a placed word, whose clue is listed under `Across` or `Down` with its clue number.
+    /// Empty `metadata` fields are left out.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The title, author, copyright and notes to store in the puzzle.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(String)` containing the ipuz JSON document.
+    /// - `Err(FormatError)` if the grid cannot be represented in the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FormatError::UncluedRun` if a run of letters on the board is not a placed word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate_with_clues, metadata::Metadata, owned::OwnedGrid, PuzzleEntry};
+    ///
+    /// let entries = [PuzzleEntry::new("TEST", "An exam"), PuzzleEntry::new("TOP", "Summit")];
+    /// let grid = generate_with_clues(&entries).unwrap().unwrap();
+    ///
+    /// let ipuz = grid.to_ipuz(&Metadata::new().title("Tiny")).unwrap();
+    /// let value: serde_json::Value = serde_json::from_str(&ipuz).unwrap();
+    /// assert_eq!(value["title"], "Tiny");
+    /// assert_eq!(value["dimensions"]["width"], grid.board[0].len());
+    /// assert_eq!(value["puzzle"][0][0], 1);
+    ///
+    /// let (loaded, metadata) = OwnedGrid::from_ipuz(&ipuz).unwrap();
+    /// assert_eq!(loaded.board, grid.board);
+    /// assert_eq!(loaded.clues(), grid.clues());
+    /// assert_eq!(metadata.title, "Tiny");
+    /// ```
+    pub fn to_ipuz(&self, metadata: &Metadata) -> Result<String, FormatError> {
+        let runs = self.clued_runs()?;
+        let height = self.board.len();
+        let width = self.board.first().map_or(0, |row| row.len());
+
+        let mut puzzle: Vec<Vec<Value>> = self
+            .board
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|ch| if *ch == ' ' { json!(BLOCK) } else { json!(0) })
+                    .collect()
+            })
+            .collect();
+        let solution: Vec<Vec<Value>> = self
+            .board
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|ch| {
+                        if *ch == ' ' {
+                            json!(BLOCK)
+                        } else {
+                            json!(ch.to_string())
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut across = Vec::new();
+        let mut down = Vec::new();
+        for (run, word) in runs {
+            puzzle[run.position.y][run.position.x] = json!(run.number);
+            let clue = json!([run.number, word.clue]);
+            match run.direction {
+                Direction::Vertical => down.push(clue),
+                _ => across.push(clue),
+            }
+        }
+
+        let mut document = Map::new();
+        document.insert("version".to_string(), json!(VERSION));
+        document.insert("kind".to_string(), json!([KIND]));
+        for (key, text) in [
+            ("title", &metadata.title),
+            ("author", &metadata.author),
+            ("copyright", &metadata.copyright),
+            ("notes", &metadata.notes),
+        ] {
+            if !text.is_empty() {
+                document.insert(key.to_string(), json!(text));
+            }
+        }
+        document.insert(
+            "dimensions".to_string(),
+            json!({ "width": width, "height": height }),
+        );
+        document.insert("block".to_string(), json!(BLOCK));
+        document.insert("puzzle".to_string(), json!(puzzle));
+        document.insert("solution".to_string(), json!(solution));
+        document.insert(
+            "clues".to_string(),
+            json!({ "Across": across, "Down": down }),
+        );
+
+        Ok(Value::Object(document).to_string())
+    }
+}
+
+/// Returns whether an ipuz cell value is a block: the `block` value of the puzzle, or
+/// `null` for an omitted cell.
+fn is_block(cell: &Value, block: &str) -> bool {
+    match cell {
+        Value::Null => true,
+        Value::String(text) => text == block,
+        Value::Object(cell) => cell.get("cell").is_some_and(|cell| is_block(cell, block)),
+        _ => false,
+    }
+}
+
+/// Reads the letter of an ipuz solution cell, which is either a string or an object with
+/// a `value` field.
+fn letter(cell: &Value) -> Option<char> {
+    match cell {
+        Value::String(text) => text.chars().next(),
+        Value::Object(cell) => cell.get("value").and_then(letter),
+        _ => None,
+    }
+}
+
+/// Reads an ipuz clue, which is either a `[number, clue]` pair or an object with `number`
+/// and `clue` fields. Numbers may be given as integers or strings.
+fn clue(entry: &Value) -> Option<(usize, String)> {
+    let (number, text) = match entry {
+        Value::Array(pair) => (pair.first()?, pair.get(1)?),
+        Value::Object(fields) => (fields.get("number")?, fields.get("clue")?),
+        _ => return None,
+    };
+    let number = match number {
+        Value::Number(number) => number.as_u64()? as usize,
+        Value::String(number) => number.trim().parse().ok()?,
+        _ => return None,
+    };
+    Some((number, text.as_str()?.to_string()))
+}
+
+impl OwnedGrid {
+    /// Writes the grid as an ipuz crossword (see `Grid::to_ipuz`).
+    pub fn to_ipuz(&self, metadata: &Metadata) -> Result<String, FormatError> {
+        self.as_grid().to_ipuz(metadata)
+    }
+
+    /// Reads an ipuz crossword.
+    ///
+    /// The board is read from the `solution` section, with blocks and omitted cells
+    /// becoming empty cells. Every run of two or more letters becomes a placed `Word`, and
+    /// the clues are matched to the words by number and direction; a word without a clue
+    /// gets an empty one.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The ipuz JSON document.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((OwnedGrid, Metadata))` with the puzzle and its title, author, copyright and notes.
+    /// - `Err(FormatError)` if the document cannot be read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FormatError` if:
+    /// - The document is not JSON, or its `dimensions` or `solution` section is missing or
+    ///   malformed (`InvalidData`).
+    /// - The board contains a letter that belongs to no run (`GridError`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::owned::OwnedGrid;
+    ///
+    /// let ipuz = r##"{
+    ///     "version": "http://ipuz.org/v2",
+    ///     "kind": ["http://ipuz.org/crossword#1"],
+    ///     "dimensions": { "width": 4, "height": 3 },
+    ///     "puzzle": [[1, 0, 0, 2], ["#", "#", "#", 0], ["#", "#", "#", 0]],
+    ///     "solution": [["T", "E", "S", "T"], ["#", "#", "#", "O"], ["#", "#", "#", "P"]],
+    ///     "clues": { "Across": [[1, "An exam"]], "Down": [[2, "Summit"]] }
+    /// }"##;
+    ///
+    /// let (grid, _) = OwnedGrid::from_ipuz(ipuz).unwrap();
+    /// let clues = grid.clues();
+    /// assert_eq!(clues.across[0].answer, "TEST");
+    /// assert_eq!(clues.down[0].clue, "Summit");
+    /// ```
+    pub fn from_ipuz(json: &str) -> Result<(Self, Metadata), FormatError> {
+        let invalid = |msg: &str| FormatError::InvalidData(msg.to_string());
+
+        let document: Value =
+            serde_json::from_str(json).map_err(|err| FormatError::InvalidData(err.to_string()))?;
+        let size = |key: &str| {
+            document["dimensions"][key]
+                .as_u64()
+                .map(|size| size as usize)
+                .ok_or_else(|| invalid("the dimensions are missing"))
+        };
+        let width = size("width")?;
+        let height = size("height")?;
+        let block = document["block"].as_str().unwrap_or(BLOCK);
+
+        let rows = document["solution"]
+            .as_array()
+            .filter(|rows| rows.len() == height)
+            .ok_or_else(|| invalid("the solution does not match the dimensions"))?;
+        let board = rows
+            .iter()
+            .map(|row| {
+                let cells = row
+                    .as_array()
+                    .filter(|cells| cells.len() == width)
+                    .ok_or_else(|| invalid("the solution does not match the dimensions"))?;
+                cells
+                    .iter()
+                    .map(|cell| {
+                        if is_block(cell, block) {
+                            Ok(' ')
+                        } else {
+                            letter(cell).ok_or_else(|| invalid("a solution cell has no letter"))
+                        }
+                    })
+                    .collect::<Result<Vec<char>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let clues = |direction: &str| -> Vec<(usize, String)> {
+            document["clues"][direction]
+                .as_array()
+                .map(|entries| entries.iter().filter_map(clue).collect())
+                .unwrap_or_default()
+        };
+        let across = clues("Across");
+        let down = clues("Down");
+
+        let grid = OwnedGrid::from_board(board, |run| {
+            let list = match run.direction {
+                Direction::Vertical => &down,
+                _ => &across,
+            };
+            list.iter()
+                .find(|(number, _)| *number == run.number)
+                .map(|(_, text)| text.clone())
+                .unwrap_or_default()
+        })?;
+
+        let text = |key: &str| document[key].as_str().unwrap_or_default().to_string();
+        let metadata = Metadata {
+            title: text("title"),
+            author: text("author"),
+            copyright: text("copyright"),
+            notes: text("notes"),
+        };
+
+        Ok((grid, metadata))
+    }
+}
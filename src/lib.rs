@@ -2,13 +2,38 @@
 //! It includes data structures for representing words, grid, and algorithms
 //! for placing words and solving the puzzle.
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::Debug;
+use std::time::Instant;
 
+use crate::dictionary::Dictionary;
 use crate::error::{Error, GridError, WordError};
-use crate::word::{Direction, Position, Word};
-
+use crate::options::{GeneratorOptions, PlacementOrder, WordOrder};
+use crate::owned::OwnedGrid;
+use crate::report::{DropReason, GenerationReport, UnplacedWord};
+use crate::rng::Rng;
+use crate::score::Layout;
+use crate::word::{Direction, Position, Word, WordRepr};
+
+pub mod connectivity;
+pub mod dictionary;
 pub mod error;
+pub mod html;
+#[cfg(feature = "serde")]
+pub mod ipuz;
+pub mod metadata;
+pub mod normalize;
+pub mod numbering;
+pub mod options;
+pub mod owned;
+pub mod pdf;
+pub mod puz;
+pub mod report;
+mod rng;
+pub mod score;
+pub mod svg;
+pub mod template;
+pub mod validation;
 pub mod word;
 
 /// `Neighbor` represents the characters and their positions in the cells immediately adjacent to a given position on the crossword grid.
@@ -29,16 +54,36 @@ pub struct Neighbor {
     pub left: Option<(Position, char)>,
 }
 
+/// `PlacementRecord` remembers how `Grid::place_word` changed the grid, so that
+/// `Grid::undo_placement` can restore the previous state without cloning the grid.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlacementRecord {
+    /// The number of columns prepended on the left of the board.
+    pub left: usize,
+    /// The number of rows prepended on the top of the board.
+    pub top: usize,
+    /// The number of columns appended on the right of the board.
+    pub right: usize,
+    /// The number of rows appended on the bottom of the board.
+    pub bottom: usize,
+    /// The cells that were empty before the word was placed, in the coordinates of the
+    /// grid after placement.
+    pub filled: Vec<Position>,
+}
+
 /// `Grid` represents the crossword puzzle board and manages the placement and validation of words.
 /// It dynamically resizes to accommodate words and provides methods for adding words and finding valid placements.
 #[derive(Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Grid<'a> {
     /// A collection of `Word`s that have been successfully placed on the grid.
     pub words: Vec<Word<'a>>,
     /// The 2D vector of characters representing the crossword board itself.
     /// Empty cells are typically represented by a space character (' ').
     pub board: Vec<Vec<char>>,
+    /// The maximum number of columns the board may grow to, or `None` for no limit.
+    pub max_width: Option<usize>,
+    /// The maximum number of rows the board may grow to, or `None` for no limit.
+    pub max_height: Option<usize>,
 }
 
 impl<'a> Default for Grid<'a> {
@@ -48,6 +93,60 @@ impl<'a> Default for Grid<'a> {
     }
 }
 
+/// Serializes the `Grid` with its `words`, `board` and the numbered `clues`
+/// (see `Grid::clues`), so consumers do not need to number the puzzle themselves.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Grid<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Grid", 3)?;
+        state.serialize_field("words", &self.words)?;
+        state.serialize_field("board", &self.board)?;
+        state.serialize_field("clues", &self.clues())?;
+        state.end()
+    }
+}
+
+impl<'a> Grid<'a> {
+    /// Builds a `Grid` from the serialized form of its words and its board.
+    ///
+    /// The crossing point of every word (its `position` and `Segment` split) is not part of
+    /// the serialized form. It is rebuilt from the first cell the word shares with a word in
+    /// the other direction, or the first character if the word crosses nothing. The words
+    /// are then checked against the board (see `Grid::from_parts`).
+    pub(crate) fn from_word_reprs(
+        reprs: &[WordRepr<'a>],
+        board: Vec<Vec<char>>,
+    ) -> Result<Self, GridError> {
+        let cells = reprs
+            .iter()
+            .map(|word| Ok((word.orientation, word.into_word(0)?.positions())))
+            .collect::<Result<Vec<_>, WordError>>()?;
+
+        let words = reprs
+            .iter()
+            .zip(cells.iter())
+            .map(|(word, (direction, positions))| {
+                let crossing = positions
+                    .iter()
+                    .position(|pos| {
+                        cells.iter().any(|(other_direction, other_positions)| {
+                            other_direction != direction && other_positions.contains(pos)
+                        })
+                    })
+                    .unwrap_or(0);
+                word.into_word(crossing)
+            })
+            .collect::<Result<Vec<_>, WordError>>()?;
+
+        Grid::from_parts(words, board)
+    }
+}
+
 /// Type alias for a function that calculates the (x, y) position of a character within a word.
 /// It takes a reference to a `Word` and an index, returning the `(x, y)` coordinates.
 type GetPosFn<'a> = Box<dyn Fn(&Word<'a>, usize) -> (usize, usize)>;
@@ -80,7 +179,272 @@ impl<'a> Grid<'a> {
         Self {
             words: vec![],
             board: vec![vec![' ']],
+            max_width: None,
+            max_height: None,
+        }
+    }
+
+    /// Creates a new, empty `Grid` that may not grow beyond the given dimensions.
+    ///
+    /// Placements that would make the board wider than `max_width` or taller than
+    /// `max_height` are rejected by `is_valid_placement` and `find_valid_placements`.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_width` - The maximum number of columns of the board.
+    /// * `max_height` - The maximum number of rows of the board.
+    ///
+    /// # Returns
+    ///
+    /// A new empty `Grid` with its size limits set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::Grid;
+    ///
+    /// let grid = Grid::with_max_size(15, 15);
+    /// assert_eq!(grid.max_width, Some(15));
+    /// assert_eq!(grid.max_height, Some(15));
+    /// assert!(grid.find_valid_placements("ABCDEFGHIJKLMNOP").unwrap().is_empty());
+    /// ```
+    pub fn with_max_size(max_width: usize, max_height: usize) -> Self {
+        Self {
+            max_width: Some(max_width),
+            max_height: Some(max_height),
+            ..Self::new()
+        }
+    }
+
+    /// Builds a `Grid` from already placed words and the board they were placed on.
+    ///
+    /// This is used to load a saved puzzle. Each word is placed by its `origin` and
+    /// `direction`, and the board is checked against the words: every letter of every
+    /// word must match the board, and every letter on the board must belong to a word.
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - The placed words, with `origin` and `direction` set.
+    /// * `board` - The board holding the letters of the words, with `' '` for empty cells.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Grid)` if the words and the board agree.
+    /// - `Err(GridError)` describing the first disagreement found.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GridError` if:
+    /// - The board is empty or not rectangular (`MalformedBoard`).
+    /// - A word has no direction (`InvalidDirection`).
+    /// - A word extends beyond the board (`OutOfBounds`).
+    /// - A board cell differs from a letter of a word (`LetterMismatch`).
+    /// - A letter on the board belongs to no word (`UncoveredLetter`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction, Position}};
+    ///
+    /// let mut test = Word::value("", 'T', "EST").unwrap().direction(Direction::Horizontal);
+    /// test.update_position();
+    /// let mut top = Word::value("", 'T', "OP").unwrap().position(3, 0).direction(Direction::Vertical);
+    /// top.update_position();
+    /// let board = vec![
+    ///     vec!['T', 'E', 'S', 'T'],
+    ///     vec![' ', ' ', ' ', 'O'],
+    ///     vec![' ', ' ', ' ', 'P'],
+    /// ];
+    ///
+    /// let grid = Grid::from_parts(vec![test, top], board.clone()).unwrap();
+    /// assert_eq!(grid.words.len(), 2);
+    /// assert_eq!(grid.get_char(Position { x: 3, y: 2 }), Some('P'));
+    ///
+    /// let mut wrong = board;
+    /// wrong[2][3] = 'T';
+    /// assert!(Grid::from_parts(vec![test, top], wrong).is_err());
+    /// ```
+    pub fn from_parts(words: Vec<Word<'a>>, board: Vec<Vec<char>>) -> Result<Self, GridError> {
+        let width = board.first().map_or(0, |row| row.len());
+        if width == 0 {
+            return Err(GridError::MalformedBoard("the board is empty".to_string()));
+        }
+        if board.iter().any(|row| row.len() != width) {
+            return Err(GridError::MalformedBoard(
+                "the rows of the board differ in length".to_string(),
+            ));
+        }
+
+        let grid = Self {
+            words,
+            board,
+            ..Self::new()
+        };
+        let mut covered = vec![vec![false; width]; grid.board.len()];
+
+        for word in grid.words.iter() {
+            if word.direction == Direction::NotSet {
+                return Err(GridError::InvalidDirection(format!(
+                    "word {} has no direction",
+                    word.segment.full_word_str()
+                )));
+            }
+
+            for (pos, expected) in word.positions().into_iter().zip(word.segment.full_word()) {
+                let Some(found) = grid.get_char(pos) else {
+                    return Err(GridError::OutOfBounds {
+                        answer: word.segment.full_word_str(),
+                        position: pos,
+                    });
+                };
+                if found != expected {
+                    return Err(GridError::LetterMismatch {
+                        answer: word.segment.full_word_str(),
+                        position: pos,
+                        expected,
+                        found,
+                    });
+                }
+                covered[pos.y][pos.x] = true;
+            }
+        }
+
+        for (y, row) in grid.board.iter().enumerate() {
+            for (x, letter) in row.iter().enumerate() {
+                if *letter != ' ' && !covered[y][x] {
+                    return Err(GridError::UncoveredLetter {
+                        position: Position { x, y },
+                        letter: *letter,
+                    });
+                }
+            }
         }
+
+        Ok(grid)
+    }
+
+    /// Builds a `Grid` holding the given anchor words at their exact positions.
+    ///
+    /// Anchors are pre-placed words, such as the long theme entry of a puzzle, that a
+    /// search started from the returned grid builds around but never moves or removes.
+    /// Every anchor must have its `position` and `direction` set; its `origin` is derived
+    /// from them. The board is just large enough to hold every anchor, with the cell
+    /// `(0, 0)` at the top-left corner, so a cell of an anchor keeps its coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `anchors` - The words to pre-place.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Grid)` holding the anchors. Without anchors, this is an empty `Grid::new()`.
+    /// - `Err(GridError)` if the anchors cannot all be placed.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GridError` if:
+    /// - An anchor has no direction (`InvalidDirection`).
+    /// - An anchor would start before the first row or column (`OutOfBounds`).
+    /// - Two anchors need different letters in the same cell (`ConflictingAnchors`).
+    /// - Two anchors overlap in the same direction or their letters run into each other
+    ///   (see `Grid::validate`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::error::GridError;
+    /// use crossword_puzzle::{Grid, word::{Word, Direction, Position}};
+    ///
+    /// let theme = Word::value("", 'C', "ROSSWORD").unwrap().position(0, 2).direction(Direction::Horizontal);
+    /// let grid = Grid::from_anchors(&[theme]).unwrap();
+    /// assert_eq!(grid.board.len(), 3);
+    /// assert_eq!(grid.get_char(Position { x: 0, y: 2 }), Some('C'));
+    ///
+    /// let cat = Word::value("", 'C', "AT").unwrap().position(0, 0).direction(Direction::Horizontal);
+    /// let dog = Word::value("", 'D', "OG").unwrap().position(1, 0).direction(Direction::Vertical);
+    /// match Grid::from_anchors(&[cat, dog]) {
+    ///     Err(GridError::ConflictingAnchors { first, second, position }) => {
+    ///         assert_eq!((first.as_str(), second.as_str()), ("CAT", "DOG"));
+    ///         assert_eq!(position, Position { x: 1, y: 0 });
+    ///     }
+    ///     other => panic!("unexpected result: {other:?}"),
+    /// }
+    /// ```
+    pub fn from_anchors(anchors: &[Word<'a>]) -> Result<Self, GridError> {
+        if anchors.is_empty() {
+            return Ok(Self::new());
+        }
+
+        let mut words = Vec::with_capacity(anchors.len());
+        for anchor in anchors.iter() {
+            let mut word = *anchor;
+            let start = match word.direction {
+                Direction::Horizontal => word.position.x,
+                Direction::Vertical => word.position.y,
+                Direction::NotSet => {
+                    return Err(GridError::InvalidDirection(format!(
+                        "anchor {} has no direction",
+                        word.segment.full_word_str()
+                    )))
+                }
+            };
+            if start < word.segment.prefix_len() {
+                return Err(GridError::OutOfBounds {
+                    answer: word.segment.full_word_str(),
+                    position: word.position,
+                });
+            }
+            word.update_position();
+            words.push(word);
+        }
+
+        let cells: Vec<Vec<Position>> = words.iter().map(Word::positions).collect();
+        let width = cells
+            .iter()
+            .flatten()
+            .map(|pos| pos.x + 1)
+            .max()
+            .unwrap_or(1);
+        let height = cells
+            .iter()
+            .flatten()
+            .map(|pos| pos.y + 1)
+            .max()
+            .unwrap_or(1);
+        let mut board = vec![vec![' '; width]; height];
+        let mut owners: Vec<Vec<Option<usize>>> = vec![vec![None; width]; height];
+
+        for (index, (word, positions)) in words.iter().zip(cells.iter()).enumerate() {
+            for (pos, letter) in positions.iter().zip(word.segment.full_word()) {
+                match owners[pos.y][pos.x] {
+                    Some(owner) if board[pos.y][pos.x] != letter => {
+                        return Err(GridError::ConflictingAnchors {
+                            first: words[owner].segment.full_word_str(),
+                            second: word.segment.full_word_str(),
+                            position: *pos,
+                        });
+                    }
+                    Some(_) => {}
+                    None => {
+                        board[pos.y][pos.x] = letter;
+                        owners[pos.y][pos.x] = Some(index);
+                    }
+                }
+            }
+        }
+
+        let grid = Self {
+            words,
+            board,
+            ..Self::new()
+        };
+        if let Err(errors) = grid.validate() {
+            return Err(errors
+                .into_iter()
+                .next()
+                .expect("validation failed with at least one error"));
+        }
+        Ok(grid)
     }
 
     /// Adds a `Word` to the grid.
@@ -113,9 +477,67 @@ impl<'a> Grid<'a> {
     /// let word = Word::value("", 'R', "UST").unwrap().direction(Direction::Horizontal);
     /// assert!(grid.add_word(word).is_ok());
     /// ```
-    pub fn add_word(&mut self, mut word: Word<'a>) -> Result<(), GridError> {
+    pub fn add_word(&mut self, word: Word<'a>) -> Result<(), GridError> {
+        self.place_word(word).map(|_| ())
+    }
+
+    /// Adds a `Word` to the grid and returns a `PlacementRecord` describing the change.
+    ///
+    /// This behaves exactly like `add_word`. The returned record can be passed to
+    /// `undo_placement` to remove the word again, which lets a search explore placements
+    /// in place instead of cloning the grid for every candidate.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - A mutable `Word` instance to be added to the grid.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(PlacementRecord)` if the word was successfully added.
+    /// - `Err(GridError)` if there was an issue with resizing the grid or filling the word.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GridError` if:
+    /// - The grid cannot be resized to fit the word.
+    /// - The word's direction is `NotSet` during filling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction}};
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.add_word(Word::value("", 'T', "EST").unwrap().direction(Direction::Horizontal)).unwrap();
+    /// let before = grid.clone();
+    ///
+    /// let word = Word::value("ATTEMP", 'T', "").unwrap().position(3, 0).direction(Direction::Vertical);
+    /// let record = grid.place_word(word).unwrap();
+    /// assert_eq!(record.top, 6);
+    /// assert_eq!(grid.board.len(), 7);
+    ///
+    /// grid.undo_placement(record);
+    /// assert_eq!(grid.board, before.board);
+    /// assert_eq!(grid.words.len(), 1);
+    /// assert_eq!(grid.words[0].origin, before.words[0].origin);
+    /// ```
+    pub fn place_word(&mut self, mut word: Word<'a>) -> Result<PlacementRecord, GridError> {
+        let width = self.board[0].len();
+        let height = self.board.len();
+        let position = word.position;
+
         self.ensure_grid_size(&mut word)?;
+        let left = word.position.x - position.x;
+        let top = word.position.y - position.y;
+        let right = self.board[0].len() - width - left;
+        let bottom = self.board.len() - height - top;
+
         word.update_position();
+        let filled = word
+            .positions()
+            .into_iter()
+            .filter(|pos| self.get_char(*pos) == Some(' '))
+            .collect();
         self.fill_word(&word)?;
 
         self.words.push(word);
@@ -124,7 +546,62 @@ impl<'a> Grid<'a> {
             word.update_position();
         }
 
-        Ok(())
+        Ok(PlacementRecord {
+            left,
+            top,
+            right,
+            bottom,
+            filled,
+        })
+    }
+
+    /// Removes the most recently placed `Word` and restores the board to its previous size.
+    ///
+    /// The `record` must be the one returned by the latest `place_word` call that has not
+    /// been undone yet; records have to be undone in reverse order of placement.
+    ///
+    /// # Arguments
+    ///
+    /// * `record` - The `PlacementRecord` returned when the word was placed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction}};
+    ///
+    /// let mut grid = Grid::new();
+    /// let record = grid
+    ///     .place_word(Word::value("", 'R', "UST").unwrap().direction(Direction::Horizontal))
+    ///     .unwrap();
+    /// grid.undo_placement(record);
+    ///
+    /// assert!(grid.words.is_empty());
+    /// assert_eq!(grid.board, vec![vec![' ']]);
+    /// ```
+    pub fn undo_placement(&mut self, record: PlacementRecord) {
+        self.words.pop();
+
+        for pos in record.filled {
+            self.board[pos.y][pos.x] = ' ';
+        }
+
+        let height = self.board.len() - record.bottom;
+        self.board.truncate(height);
+        for row in self.board.iter_mut() {
+            let width = row.len() - record.right;
+            row.truncate(width);
+        }
+
+        self.board.drain(..record.top);
+        for row in self.board.iter_mut() {
+            row.drain(..record.left);
+        }
+
+        for word in self.words.iter_mut() {
+            word.position.x -= record.left;
+            word.position.y -= record.top;
+            word.update_position();
+        }
     }
 
     /// Recursively resizes the grid by adding empty cells in the specified direction.
@@ -243,9 +720,9 @@ impl<'a> Grid<'a> {
         let segment = &word.segment;
 
         let prefix_pos = if word.direction == Direction::Horizontal {
-            position.x as isize - segment.prefix.len() as isize
+            position.x as isize - segment.prefix_len() as isize
         } else {
-            position.y as isize - segment.prefix.len() as isize
+            position.y as isize - segment.prefix_len() as isize
         };
 
         if prefix_pos < 0 {
@@ -260,10 +737,10 @@ impl<'a> Grid<'a> {
         }
 
         let suffix_pos = if word.direction == Direction::Horizontal {
-            let length = position.x.saturating_add(segment.suffix.len()) + 1;
+            let length = position.x.saturating_add(segment.suffix_len()) + 1;
             self.board[0].len() as isize - length as isize
         } else {
-            let length = position.y.saturating_add(segment.suffix.len()) + 1;
+            let length = position.y.saturating_add(segment.suffix_len()) + 1;
             self.board.len() as isize - length as isize
         };
         if suffix_pos < 0 {
@@ -629,8 +1106,10 @@ impl<'a> Grid<'a> {
     ///
     /// This function performs a series of checks to ensure that placing the given `word`
     /// on the grid at its specified position and direction does not violate any crossword rules.
-    /// It verifies that the word does not overlap with existing characters incorrectly and that
-    /// adjacent cells are empty where required.
+    /// It verifies that the word does not overlap with existing characters incorrectly, that
+    /// it shares no cell with a placed word running in the same direction, that adjacent
+    /// cells are empty where required, and that the board would stay within `max_width` and
+    /// `max_height`.
     ///
     /// # Arguments
     ///
@@ -663,8 +1142,18 @@ impl<'a> Grid<'a> {
     /// // A word that conflicts (e.g., 'B' from BEST conflicts with 'T' from TEST)
     /// let word3 = Word::value("BES", 'T', "").unwrap().position(4, 0).direction(Direction::Horizontal);
     /// assert!(!grid.is_valid_placement(&word3).unwrap());
+    ///
+    /// // A word that would extend the vertical word AS into ASH
+    /// let mut grid = Grid::new();
+    /// grid.add_word(Word::value("", 'A', "S").unwrap().direction(Direction::Vertical)).unwrap();
+    /// let ash = Word::value("", 'A', "SH").unwrap().position(0, 0).direction(Direction::Vertical);
+    /// assert!(!grid.is_valid_placement(&ash).unwrap());
     /// ```
     pub fn is_valid_placement(&self, word: &Word<'a>) -> Result<bool, GridError> {
+        if !self.fits_within_bounds(word) || self.overlaps_parallel_word(word) {
+            return Ok(false);
+        }
+
         let mut pos = word.position;
 
         // Check prefix
@@ -681,6 +1170,134 @@ impl<'a> Grid<'a> {
         Ok(true)
     }
 
+    /// Returns `true` if `word` would share a cell with a placed word running in the same
+    /// direction, for example by extending it into a longer word.
+    fn overlaps_parallel_word(&self, word: &Word<'a>) -> bool {
+        let prefix_len = word.segment.prefix_len() as isize;
+        let length = word.segment.len() as isize;
+        let (x, y) = (word.position.x as isize, word.position.y as isize);
+        self.words
+            .iter()
+            .filter(|placed| placed.direction == word.direction)
+            .any(|placed| {
+                let (origin_x, origin_y) = (placed.origin.x as isize, placed.origin.y as isize);
+                let placed_len = placed.segment.len() as isize;
+                match word.direction {
+                    Direction::Horizontal => {
+                        let start = x - prefix_len;
+                        origin_y == y && start < origin_x + placed_len && origin_x < start + length
+                    }
+                    Direction::Vertical => {
+                        let start = y - prefix_len;
+                        origin_x == x && start < origin_y + placed_len && origin_y < start + length
+                    }
+                    Direction::NotSet => false,
+                }
+            })
+    }
+
+    /// Checks whether placing a `Word` would keep the board within `max_width` and `max_height`.
+    ///
+    /// The board grows when a word extends past its edges, so this computes the size the board
+    /// would have after the word is added and compares it with the limits. A grid without limits
+    /// accepts every placement.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - A reference to the `Word` whose placement is checked.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the board would stay within its limits after adding `word`.
+    /// - `false` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction}};
+    ///
+    /// let mut grid = Grid::with_max_size(5, 5);
+    /// grid.add_word(Word::value("", 'T', "EST").unwrap().direction(Direction::Horizontal)).unwrap();
+    ///
+    /// // Crossing the last 'T' with "ATTEMPT" would need 7 rows.
+    /// let tall = Word::value("ATTEMP", 'T', "").unwrap().position(3, 0).direction(Direction::Vertical);
+    /// assert!(!grid.fits_within_bounds(&tall));
+    ///
+    /// let short = Word::value("", 'T', "OP").unwrap().position(3, 0).direction(Direction::Vertical);
+    /// assert!(grid.fits_within_bounds(&short));
+    /// ```
+    pub fn fits_within_bounds(&self, word: &Word<'a>) -> bool {
+        let (new_width, new_height) = self.grown_size(word);
+        self.max_width.is_none_or(|max| new_width <= max as isize)
+            && self.max_height.is_none_or(|max| new_height <= max as isize)
+    }
+
+    /// Returns the width and height the board would have after adding `word`.
+    fn grown_size(&self, word: &Word<'a>) -> (isize, isize) {
+        let prefix_len = word.segment.prefix_len() as isize;
+        let suffix_len = word.segment.suffix_len() as isize;
+        let width = self.board[0].len() as isize;
+        let height = self.board.len() as isize;
+
+        match word.direction {
+            Direction::Horizontal => {
+                let start = word.position.x as isize - prefix_len;
+                let end = word.position.x as isize + suffix_len + 1;
+                (width.max(end) - start.min(0), height)
+            }
+            Direction::Vertical => {
+                let start = word.position.y as isize - prefix_len;
+                let end = word.position.y as isize + suffix_len + 1;
+                (width, height.max(end) - start.min(0))
+            }
+            Direction::NotSet => (width, height),
+        }
+    }
+
+    /// Counts how many letters of a `Word` would land on cells that already hold the same letter.
+    ///
+    /// The word does not need to be added to the grid; its cells are derived from its
+    /// `position`, `direction` and segment lengths.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - A reference to the `Word` whose crossings are counted.
+    ///
+    /// # Returns
+    ///
+    /// The number of cells the word would share with words already on the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction}};
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.add_word(Word::value("", 'T', "EST").unwrap().direction(Direction::Horizontal)).unwrap();
+    ///
+    /// let word = Word::value("", 'T', "OP").unwrap().position(3, 0).direction(Direction::Vertical);
+    /// assert_eq!(grid.count_crossings(&word), 1);
+    /// ```
+    pub fn count_crossings(&self, word: &Word<'a>) -> usize {
+        let prefix_len = word.segment.prefix_len() as isize;
+
+        word.segment
+            .full_word()
+            .into_iter()
+            .enumerate()
+            .filter(|(index, ch)| {
+                let offset = *index as isize - prefix_len;
+                let (dx, dy) = match word.direction {
+                    Direction::Horizontal => (offset, 0),
+                    Direction::Vertical => (0, offset),
+                    Direction::NotSet => return false,
+                };
+                self.get_neighbor_at_offset(word.position, dx, dy)
+                    .is_some_and(|(_, board_ch)| board_ch == *ch)
+            })
+            .count()
+    }
+
     /// Helper function to check the placement of a word segment (prefix or suffix).
     ///
     /// This function iterates through the characters of a word segment and validates their
@@ -809,7 +1426,7 @@ impl<'a> Grid<'a> {
 
     /// Finds all valid placements for a given word string on the current grid.
     ///
-    /// This function iterates through each character of the `word_str` to consider it as a potential
+    /// This function iterates through each character (not byte) of the `word_str` to consider it as a potential
     /// crossing point. For each potential crossing, it attempts to find valid horizontal and vertical
     /// placements on the grid. If the grid is empty, it considers initial horizontal and vertical placements.
     ///
@@ -838,14 +1455,20 @@ impl<'a> Grid<'a> {
     ///
     /// let placements = grid.find_valid_placements("TEST").unwrap();
     /// assert!(!placements.is_empty());
+    ///
+    /// // Answers with multi-byte letters are split on character boundaries.
+    /// let mut grid = Grid::new();
+    /// grid.add_word(Word::value("", 'İ', "ZMİR").unwrap().direction(Direction::Horizontal)).unwrap();
+    /// let placements = grid.find_valid_placements("KİRAZ").unwrap();
+    /// assert!(placements.iter().all(|w| w.segment.full_word_str() == "KİRAZ"));
+    /// assert!(placements.iter().any(|w| w.segment.prefix == "K" && w.segment.crossed == 'İ'));
     /// ```
     pub fn find_valid_placements(&self, word_str: &'a str) -> Result<Vec<Word<'a>>, GridError> {
         let mut placements = Vec::new();
 
-        for index in 0..word_str.len() {
-            let (prefix, remain) = word_str.split_at(index);
-            let (mid, suffix) = remain.split_at(1);
-            let crossed = mid.chars().next().unwrap();
+        for (index, crossed) in word_str.char_indices() {
+            let prefix = &word_str[..index];
+            let suffix = &word_str[index + crossed.len_utf8()..];
 
             if self.words.is_empty() {
                 placements.extend(self.handle_initial_placements(prefix, crossed, suffix)?);
@@ -872,6 +1495,7 @@ impl<'a> Grid<'a> {
     ///
     /// It generates both horizontal and vertical `Word` placements for the given segment,
     /// assuming an empty grid where the word can be placed at the origin (0,0).
+    /// Placements that would exceed `max_width` or `max_height` are left out.
     ///
     /// # Arguments
     ///
@@ -912,6 +1536,8 @@ impl<'a> Grid<'a> {
 
         let vertical_word = Word::value(prefix, crossed, suffix)?.direction(Direction::Vertical);
         placements.push(vertical_word);
+
+        placements.retain(|word| self.fits_within_bounds(word));
         Ok(placements)
     }
 
@@ -942,10 +1568,89 @@ impl<'a> Grid<'a> {
     }
 }
 
+/// `PuzzleEntry` pairs an answer with the clue that should be attached to it once placed.
+/// It is the input type for `generate_with_clues`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PuzzleEntry<'a> {
+    /// The answer to be placed on the grid.
+    pub answer: &'a str,
+    /// The clue associated with the answer.
+    pub clue: &'a str,
+    /// The answer as it is shown in answer lists, such as `ICE CREAM` for `ICECREAM`, or
+    /// an empty string to show the answer itself.
+    pub display: &'a str,
+}
+
+impl<'a> PuzzleEntry<'a> {
+    /// Creates a new `PuzzleEntry` from an answer and its clue.
+    ///
+    /// # Arguments
+    ///
+    /// * `answer` - The string slice representing the answer.
+    /// * `clue` - The string slice representing the clue for the answer.
+    ///
+    /// # Returns
+    ///
+    /// A new `PuzzleEntry` instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::PuzzleEntry;
+    ///
+    /// let entry = PuzzleEntry::new("RUST", "A systems programming language");
+    /// assert_eq!(entry.answer, "RUST");
+    /// assert_eq!(entry.clue, "A systems programming language");
+    /// ```
+    pub fn new(answer: &'a str, clue: &'a str) -> Self {
+        Self {
+            answer,
+            clue,
+            display: "",
+        }
+    }
+
+    /// Sets the form in which the answer is shown in answer lists.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate_with_clues, PuzzleEntry};
+    ///
+    /// let entries = [
+    ///     PuzzleEntry::new("ICECREAM", "").display("ICE CREAM"),
+    ///     PuzzleEntry::new("CREAM", ""),
+    /// ];
+    /// let grid = generate_with_clues(&entries).unwrap().unwrap();
+    /// let clues = grid.clues();
+    /// let clue = clues.across.iter().chain(clues.down.iter())
+    ///     .find(|clue| clue.answer == "ICECREAM")
+    ///     .unwrap();
+    /// assert_eq!(clue.display_answer(), "ICE CREAM");
+    /// ```
+    pub fn display(mut self, display: &'a str) -> Self {
+        self.display = display;
+        self
+    }
+}
+
+impl<'a> From<&'a str> for PuzzleEntry<'a> {
+    /// Creates a `PuzzleEntry` with an empty clue.
+    fn from(answer: &'a str) -> Self {
+        Self::new(answer, "")
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct PossibleWord<'a> {
     /// The string value of the word.
     pub value: &'a str,
+    /// The clue that will be attached to the word once it is placed.
+    pub clue: &'a str,
+    /// The display form that will be attached to the word once it is placed.
+    pub display: &'a str,
     /// The number of remaining attempts to place this word on the grid.
     pub remaining: usize,
 }
@@ -976,18 +1681,53 @@ impl<'a> PossibleWord<'a> {
     pub fn new(value: &'a str) -> Self {
         Self {
             value,
+            clue: "",
+            display: "",
             remaining: 3,
         }
     }
-}
 
-/// Calculates the squared Euclidean distance between two `Position`s.
-///
-/// This function is used to determine the distance between two points on the grid
-/// without computing the square root, which is often sufficient for comparisons.
-/// It's particularly useful for sorting placements by their proximity to a reference point.
-///
-/// # Arguments
+    /// Sets the clue for the word.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `clue` - The string slice representing the clue for the word.
+    ///
+    /// # Returns
+    ///
+    /// The `PossibleWord` instance with its `clue` updated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::PossibleWord;
+    ///
+    /// let pw = PossibleWord::new("HELLO").clue("A greeting");
+    /// assert_eq!(pw.clue, "A greeting");
+    /// ```
+    pub fn clue(mut self, clue: &'a str) -> Self {
+        self.clue = clue;
+        self
+    }
+
+    /// Sets the form in which the word is shown in answer lists.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn display(mut self, display: &'a str) -> Self {
+        self.display = display;
+        self
+    }
+}
+
+/// Calculates the squared Euclidean distance between two `Position`s.
+///
+/// This function is used to determine the distance between two points on the grid
+/// without computing the square root, which is often sufficient for comparisons.
+/// It's particularly useful for sorting placements by their proximity to a reference point.
+///
+/// # Arguments
 ///
 /// * `a` - The first `Position`.
 /// * `b` - The second `Position`.
@@ -1003,10 +1743,11 @@ fn squared_euclidean(a: Position, b: Position) -> i32 {
 
 /// A backtracking function to generate the crossword puzzle.
 ///
-/// This function attempts to place words one by one onto the grid using a recursive
+/// This function attempts to place words one by one onto the grid using a
 /// backtracking approach. It explores possible placements for each word. If a word
 /// cannot be placed in the current attempt and has remaining retries, it is re-queued
-/// for a later attempt. If a placement leads to a dead end, the function backtracks
+/// for a later attempt; once its retries are used up it is left out and the remaining
+/// words are still placed. If a placement leads to a dead end, the function backtracks
 /// to try another path.
 ///
 /// # Arguments
@@ -1024,35 +1765,328 @@ fn squared_euclidean(a: Position, b: Position) -> i32 {
 /// # Errors
 ///
 /// Returns an `Error` if `Grid::find_valid_placements` or `Grid::add_word` return an error.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+/// use crossword_puzzle::{backtrack, Grid, PossibleWord};
+///
+/// // "CONSECTETUR" never fits on a 7x7 board and has no retries left, so it is left
+/// // out, and the words queued after it are still placed.
+/// let mut words: VecDeque<PossibleWord> = ["LOREM", "IPSUM", "DOLOR"]
+///     .into_iter()
+///     .map(PossibleWord::new)
+///     .collect();
+/// let mut long = PossibleWord::new("CONSECTETUR");
+/// long.remaining = 1;
+/// words.insert(1, long);
+/// let grid = backtrack(Grid::with_max_size(7, 7), words).unwrap().unwrap();
+///
+/// let answers: Vec<String> = grid.words.iter().map(|w| w.segment.full_word_str()).collect();
+/// assert_eq!(answers, vec!["LOREM", "IPSUM", "DOLOR"]);
+/// ```
 pub fn backtrack<'a>(
+    grid: Grid<'a>,
+    words_to_place: VecDeque<PossibleWord<'a>>,
+) -> Result<Option<Grid<'a>>, Error> {
+    backtrack_with(grid, words_to_place, &GeneratorOptions::default())
+}
+
+/// A backtracking function to generate the crossword puzzle using the given `GeneratorOptions`.
+///
+/// This is the search behind `backtrack`. The `placement_order` of the options decides which
+/// placements are tried first, and the search stops early once `max_words` words are placed
+/// or the `timeout` or `max_nodes` budget runs out, returning the grid built so far.
+/// The number of attempts per word is taken from each `PossibleWord::remaining`, and the board
+/// size limits from the `Grid` itself.
+///
+/// If the options carry a `seed`, the queue is shuffled and then stably re-sorted by the
+/// `word_order`, and candidate placements are shuffled before they are sorted. Words and
+/// placements that the orderings consider equal are therefore tried in a seed-dependent
+/// order, and the same seed always yields the same grid.
+///
+/// # Arguments
+///
+/// * `grid` - The current `Grid` state.
+/// * `words_to_place` - A `VecDeque` containing `PossibleWord`s that still need to be placed,
+///   along with their remaining placement attempts.
+/// * `options` - The `GeneratorOptions` controlling the search.
+///
+/// # Returns
+///
+/// - `Ok(Some(Grid))` if a complete and valid crossword puzzle grid is successfully generated.
+/// - `Ok(None)` if no valid grid can be generated from the given words after all attempts.
+/// - `Err(Error)` if an error occurs during grid operations (e.g., invalid word segments).
+///
+/// # Errors
+///
+/// Returns an `Error` if `Grid::find_valid_placements` or `Grid::add_word` return an error.
+pub fn backtrack_with<'a>(
+    grid: Grid<'a>,
+    words_to_place: VecDeque<PossibleWord<'a>>,
+    options: &GeneratorOptions,
+) -> Result<Option<Grid<'a>>, Error> {
+    run_search(
+        grid,
+        words_to_place,
+        options,
+        &mut GenerationReport::default(),
+    )
+}
+
+/// Prepares the seeded randomisation described in `backtrack_with` and runs the search,
+/// recording unplaced words and search statistics in `report`. If the options require a
+/// connected grid, a disconnected result is rejected and its islands are recorded in
+/// `report.islands`.
+fn run_search<'a>(
     grid: Grid<'a>,
     mut words_to_place: VecDeque<PossibleWord<'a>>,
+    options: &GeneratorOptions,
+    report: &mut GenerationReport<'a>,
 ) -> Result<Option<Grid<'a>>, Error> {
-    if let Some(mut current_word) = words_to_place.pop_front() {
-        let mut placements = grid.find_valid_placements(current_word.value)?;
-        if placements.is_empty() && current_word.remaining > 1 {
-            current_word.remaining = current_word.remaining.saturating_sub(1);
-            words_to_place.push_back(current_word);
+    let mut rng = options.seed.map(Rng::new);
+    if let Some(rng) = rng.as_mut() {
+        rng.shuffle(words_to_place.make_contiguous());
+        sort_words(&mut words_to_place, options.word_order);
+    }
 
-            return backtrack(grid, words_to_place);
+    let grid = search(grid, words_to_place, options, &mut rng, report)?;
+    match grid {
+        Some(grid) if options.require_connected && !grid.is_connected() => {
+            report.islands = grid.islands();
+            Ok(None)
         }
+        grid => Ok(grid),
+    }
+}
 
-        let ref_position = Position {
-            x: grid.board[0].len() / 2,
-            y: grid.board.len() / 2,
+/// A level of the explicit search stack used by `search`.
+///
+/// Each frame belongs to one word being placed: it keeps the candidate placements of that
+/// word, which of them is currently applied to the grid, and how far the `QueueChange` log
+/// reached when the word was taken from the queue, so the search can come back to the
+/// frame and try the next candidate.
+struct Frame<'a> {
+    /// The word being placed at this level.
+    word: PossibleWord<'a>,
+    /// The length of the `QueueChange` log right after `word` was taken from the queue.
+    queue_log_len: usize,
+    /// The candidate placements of `word`, in the order they are tried.
+    placements: Vec<Word<'a>>,
+    /// The index of the next candidate placement to try.
+    next: usize,
+    /// The length of `GenerationReport::unplaced` when the frame was created.
+    unplaced_len: usize,
+    /// The record of the placement currently applied to the grid, if any.
+    applied: Option<PlacementRecord>,
+}
+
+/// A change made to the queue of words during `search`, logged so that it can be undone
+/// when the search backtracks.
+enum QueueChange<'a> {
+    /// The word was taken from the front of the queue.
+    PoppedFront(PossibleWord<'a>),
+    /// A word was pushed to the back of the queue.
+    PushedBack,
+}
+
+/// Undoes the changes logged after the first `len` entries of `log`, in reverse order.
+fn rewind_queue<'a>(
+    queue: &mut VecDeque<PossibleWord<'a>>,
+    log: &mut Vec<QueueChange<'a>>,
+    len: usize,
+) {
+    while log.len() > len {
+        match log.pop() {
+            Some(QueueChange::PoppedFront(word)) => queue.push_front(word),
+            Some(QueueChange::PushedBack) => {
+                queue.pop_back();
+            }
+            None => break,
+        }
+    }
+}
+
+/// The search behind `backtrack_with`.
+///
+/// The search runs as a loop over an explicit stack of `Frame`s rather than recursing once
+/// per word, and it applies and undoes placements on a single `Grid` instead of cloning the
+/// grid for every candidate. The queue of words is shared by all frames as well: every change
+/// to it is logged as a `QueueChange` and rewound on backtracking. This keeps memory and
+/// stack usage flat for long word lists.
+///
+/// When `rng` is set, the candidate placements of every word are shuffled before they are
+/// sorted by the `placement_order`, so placements the order considers equal are tried in
+/// a seed-dependent order.
+///
+/// Words given up on are appended to `report.unplaced`. If a branch turns out to be a dead
+/// end, the entries it added are removed again, so the report always describes the
+/// returned grid.
+///
+/// If the `timeout` or `max_nodes` budget of the options runs out, the grid built so far is
+/// returned, and the words that were not tried yet are reported as
+/// `DropReason::BudgetExhausted`.
+fn search<'a>(
+    mut grid: Grid<'a>,
+    mut words_to_place: VecDeque<PossibleWord<'a>>,
+    options: &GeneratorOptions,
+    rng: &mut Option<Rng>,
+    report: &mut GenerationReport<'a>,
+) -> Result<Option<Grid<'a>>, Error> {
+    let started = Instant::now();
+    let mut stack: Vec<Frame<'a>> = Vec::new();
+    let mut queue_log: Vec<QueueChange<'a>> = Vec::new();
+
+    loop {
+        let out_of_time = options
+            .timeout
+            .is_some_and(|timeout| started.elapsed() >= timeout);
+        let out_of_nodes = options
+            .max_nodes
+            .is_some_and(|max_nodes| report.attempts >= max_nodes);
+        if out_of_time || out_of_nodes {
+            report.budget_exhausted = true;
+            report
+                .unplaced
+                .extend(words_to_place.iter().map(|word| UnplacedWord {
+                    answer: word.value,
+                    clue: word.clue,
+                    reason: DropReason::BudgetExhausted,
+                }));
+            return Ok((!grid.words.is_empty()).then_some(grid));
+        }
+
+        if options
+            .max_words
+            .is_some_and(|max_words| grid.words.len() >= max_words)
+        {
+            report
+                .unplaced
+                .extend(words_to_place.iter().map(|word| UnplacedWord {
+                    answer: word.value,
+                    clue: word.clue,
+                    reason: DropReason::WordLimitReached,
+                }));
+            return Ok(Some(grid));
+        }
+
+        let Some(mut current_word) = words_to_place.pop_front() else {
+            return Ok(Some(grid));
         };
-        placements.sort_by_key(|w| squared_euclidean(w.position, ref_position));
-        for placement_word in placements {
-            let mut new_grid = grid.clone();
-            new_grid.add_word(placement_word)?;
+        queue_log.push(QueueChange::PoppedFront(current_word.clone()));
 
-            if let Some(final_grid) = backtrack(new_grid, words_to_place.clone())? {
-                return Ok(Some(final_grid));
+        let mut placements = grid.find_valid_placements(current_word.value)?;
+        if placements.is_empty() {
+            if current_word.remaining > 1 {
+                current_word.remaining = current_word.remaining.saturating_sub(1);
+                words_to_place.push_back(current_word);
+                queue_log.push(QueueChange::PushedBack);
+            } else {
+                report.unplaced.push(UnplacedWord {
+                    answer: current_word.value,
+                    clue: current_word.clue,
+                    reason: crossing_failure(&grid, current_word.value),
+                });
+            }
+            continue;
+        }
+
+        if let Some(rng) = rng.as_mut() {
+            rng.shuffle(&mut placements);
+        }
+        sort_placements(&grid, &mut placements, options.placement_order);
+        stack.push(Frame {
+            word: current_word,
+            queue_log_len: queue_log.len(),
+            placements,
+            next: 0,
+            unplaced_len: report.unplaced.len(),
+            applied: None,
+        });
+
+        // Apply the next untried placement of the innermost frame. A frame whose placements
+        // are all used up is a dead end: it is removed and the search resumes with the next
+        // placement of the frame before it.
+        loop {
+            let Some(frame) = stack.last_mut() else {
+                return Ok(None);
+            };
+
+            if let Some(record) = frame.applied.take() {
+                grid.undo_placement(record);
+                report.unplaced.truncate(frame.unplaced_len);
+            }
+            rewind_queue(&mut words_to_place, &mut queue_log, frame.queue_log_len);
+
+            if frame.next < frame.placements.len() {
+                if frame.next > 0 {
+                    report.backtracks += 1;
+                }
+                let placement_word = frame.placements[frame.next]
+                    .clue(frame.word.clue)
+                    .display(frame.word.display);
+                frame.next += 1;
+                frame.applied = Some(grid.place_word(placement_word)?);
+                report.attempts += 1;
+                break;
+            }
+
+            stack.pop();
+            if !grid.words.is_empty() || words_to_place.is_empty() {
+                return Ok(Some(grid));
             }
         }
     }
+}
 
-    Ok((!grid.words.is_empty() || words_to_place.is_empty()).then_some(grid))
+/// Sorts candidate placements according to the given `PlacementOrder`.
+///
+/// # Arguments
+///
+/// * `grid` - The `Grid` the placements were found on.
+/// * `placements` - The candidate placements to sort in place.
+/// * `order` - The `PlacementOrder` to apply.
+fn sort_placements<'a>(grid: &Grid<'a>, placements: &mut [Word<'a>], order: PlacementOrder) {
+    let ref_position = Position {
+        x: grid.board[0].len() / 2,
+        y: grid.board.len() / 2,
+    };
+
+    match order {
+        PlacementOrder::CenterFirst => {
+            placements.sort_by_key(|w| squared_euclidean(w.position, ref_position));
+        }
+        PlacementOrder::MostCrossings => {
+            placements.sort_by_key(|w| {
+                (
+                    std::cmp::Reverse(grid.count_crossings(w)),
+                    squared_euclidean(w.position, ref_position),
+                )
+            });
+        }
+        PlacementOrder::AsFound => {}
+    }
+}
+
+/// Sorts a queue of words according to the given `WordOrder`.
+///
+/// The sort is stable, so words of equal length keep their relative order.
+///
+/// # Arguments
+///
+/// * `words` - The queue of `PossibleWord`s to sort in place.
+/// * `order` - The `WordOrder` to apply.
+fn sort_words(words: &mut VecDeque<PossibleWord<'_>>, order: WordOrder) {
+    match order {
+        WordOrder::LongestFirst => words
+            .make_contiguous()
+            .sort_by_key(|w| std::cmp::Reverse(w.value.chars().count())),
+        WordOrder::ShortestFirst => words
+            .make_contiguous()
+            .sort_by_key(|w| w.value.chars().count()),
+        WordOrder::AsGiven => {}
+    }
 }
 
 /// Eliminates words that do not share any common characters with other words.
@@ -1060,7 +2094,11 @@ pub fn backtrack<'a>(
 /// This function filters the initial list of words, keeping only those that have at least
 /// one common character with another word in the list. This helps in reducing the search space
 /// for the crossword generation by focusing on words that can actually intersect.
-/// The words are then sorted by length in reverse order (longest first).
+/// The shared characters are looked up in the letter index of a `Dictionary` built from
+/// the list, so the cost grows with the total length of the words rather than with the
+/// square of their number.
+/// The words are then sorted by length in reverse order (longest first); words of equal
+/// length keep the order they were given in.
 ///
 /// # Arguments
 ///
@@ -1084,36 +2122,70 @@ pub fn backtrack<'a>(
 /// assert_eq!(filtered_words.len(), 3);
 /// ```
 pub fn eliminate_words<'a>(words_to_place: &[&'a str]) -> VecDeque<PossibleWord<'a>> {
-    let mut filtered_words_set = std::collections::HashSet::new();
-
-    for i in 0..words_to_place.len() {
-        let word1 = words_to_place[i];
-        let chars1: std::collections::HashSet<char> = word1.chars().collect();
+    let entries: Vec<PuzzleEntry<'a>> = words_to_place
+        .iter()
+        .map(|word| PuzzleEntry::from(*word))
+        .collect();
+    eliminate_entries(&entries)
+}
 
-        let mut has_common_char_with_other_word = false;
-        for (j, word2) in words_to_place.iter().enumerate() {
-            if i == j {
-                continue;
-            }
-            let chars2: std::collections::HashSet<char> = word2.chars().collect();
+/// Eliminates entries whose answers do not share any common characters with other answers.
+///
+/// This behaves like `eliminate_words`, but keeps each answer paired with its clue.
+/// Entries with the same answer and different clues are kept as separate words,
+/// while exact duplicates are only kept once.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `PuzzleEntry` values representing the initial list of answers and clues.
+///
+/// # Returns
+///
+/// A `VecDeque<PossibleWord>` containing the filtered and sorted words, each carrying its clue.
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::{eliminate_entries, PuzzleEntry};
+///
+/// let entries = &[
+///     PuzzleEntry::new("RUST", "Iron oxide"),
+///     PuzzleEntry::new("RUST", "A programming language"),
+///     PuzzleEntry::new("TEST", "Exam"),
+///     PuzzleEntry::new("ZIP", "Fastener"),
+/// ];
+/// let filtered = eliminate_entries(entries);
+///
+/// // Both "RUST" entries are kept, "ZIP" is eliminated.
+/// assert_eq!(filtered.len(), 3);
+/// assert_eq!(filtered.iter().filter(|w| w.value == "RUST").count(), 2);
+/// ```
+pub fn eliminate_entries<'a>(entries: &[PuzzleEntry<'a>]) -> VecDeque<PossibleWord<'a>> {
+    let answers: Vec<&str> = entries.iter().map(|entry| entry.answer).collect();
+    let dictionary = Dictionary::from_words(&answers);
+    let mut occurrences: HashMap<&str, usize> = HashMap::new();
+    for answer in answers.iter() {
+        *occurrences.entry(answer.trim()).or_default() += 1;
+    }
 
-            if chars1.intersection(&chars2).next().is_some() {
-                has_common_char_with_other_word = true;
-                break;
-            }
-        }
+    let mut filtered_entries: Vec<PuzzleEntry<'a>> = Vec::new();
+    let mut seen: HashSet<PuzzleEntry<'a>> = HashSet::new();
+    for entry in entries.iter() {
+        let answer = entry.answer.trim();
+        let has_common_char_with_other_word = !answer.is_empty()
+            && (occurrences[answer] > 1
+                || answer.chars().any(|ch| dictionary.count_containing(ch) > 1));
 
-        if has_common_char_with_other_word {
-            filtered_words_set.insert(word1);
+        if has_common_char_with_other_word && seen.insert(*entry) {
+            filtered_entries.push(*entry);
         }
     }
 
-    let mut filtered_words: Vec<&'a str> = filtered_words_set.into_iter().collect();
-    filtered_words.sort_by_key(|c| std::cmp::Reverse(c.len()));
+    filtered_entries.sort_by_key(|e| std::cmp::Reverse(e.answer.chars().count()));
     VecDeque::from(
-        filtered_words
+        filtered_entries
             .iter()
-            .map(|w| PossibleWord::new(w))
+            .map(|e| PossibleWord::new(e.answer).clue(e.clue).display(e.display))
             .collect::<Vec<_>>(),
     )
 }
@@ -1161,14 +2233,1186 @@ pub fn eliminate_words<'a>(words_to_place: &[&'a str]) -> VecDeque<PossibleWord<
 ///     Err(e) => eprintln!("Error generating puzzle: {}", e),
 /// }
 /// ```
+///
+/// Answers may contain any uppercase Unicode letters; each character occupies one cell.
+///
+/// ```
+/// use crossword_puzzle::generate;
+///
+/// let grid = generate(&["ÄRGER", "GRÜN", "ÉTÉ", "TÜR"]).unwrap().unwrap();
+/// for word in grid.words.iter() {
+///     let letters: Vec<char> = word
+///         .positions()
+///         .iter()
+///         .map(|pos| grid.get_char(*pos).unwrap())
+///         .collect();
+///     assert_eq!(letters, word.segment.full_word());
+/// }
+/// ```
 pub fn generate<'a>(words: &[&'a str]) -> Result<Option<Grid<'a>>, Error> {
-    for word in words.iter() {
-        if word.chars().any(|c| c.is_lowercase()) {
-            return Err(Error::WordError(WordError::LowercaseCharactersInSegment));
+    generate_with(&GeneratorOptions::default(), words)
+}
+
+/// The number of distinct layouts compared by `generate_with_max_size`.
+const MAX_SIZE_LAYOUTS: usize = 8;
+
+/// Generates a crossword puzzle grid that fits within a fixed board size.
+///
+/// This works like `generate`, but the board never grows beyond `max_width` columns and
+/// `max_height` rows, which makes the result suitable for printed templates such as
+/// 15x15 or 21x21. Words that cannot be placed inside the limits are left out.
+///
+/// Several distinct layouts are searched with `generate_layouts`, and the one with the
+/// best `LayoutScore` is returned: the most placed words, then the most crossings, then
+/// the densest board.
+///
+/// # Arguments
+///
+/// * `words` - A slice of string slices (`&[&'a str]`) representing the words
+///   to be used in the crossword puzzle.
+/// * `max_width` - The maximum number of columns of the board.
+/// * `max_height` - The maximum number of rows of the board.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(Some(Grid))` with the best layout found within the limits.
+/// - `Ok(None)` if no word can be placed within the limits.
+/// - `Err(Error)` if an error occurs during the generation process (e.g.,
+///   invalid word segments).
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::{generate_layouts, generate_with_max_size};
+/// use crossword_puzzle::options::GeneratorOptions;
+///
+/// let words = &["LOREM", "IPSUM", "DOLOR", "SIT", "AMET", "CONSECTETUR"];
+/// let grid = generate_with_max_size(words, 7, 7).unwrap().unwrap();
+/// assert!(grid.board.len() <= 7);
+/// assert!(grid.board.iter().all(|row| row.len() <= 7));
+/// // "CONSECTETUR" is too long to fit.
+/// assert!(grid.words.iter().all(|w| w.segment.full_word_str() != "CONSECTETUR"));
+///
+/// // No other layout within the limits scores better.
+/// let options = GeneratorOptions::new().max_size(7, 7);
+/// for layout in generate_layouts(&options, words, 4).unwrap() {
+///     assert!(layout.score <= grid.score());
+/// }
+/// ```
+pub fn generate_with_max_size<'a>(
+    words: &[&'a str],
+    max_width: usize,
+    max_height: usize,
+) -> Result<Option<Grid<'a>>, Error> {
+    let options = GeneratorOptions::default().max_size(max_width, max_height);
+    let layouts = generate_layouts(&options, words, MAX_SIZE_LAYOUTS)?;
+    Ok(layouts.into_iter().next().map(|layout| layout.grid))
+}
+
+/// Generates a crossword puzzle grid from a list of answers paired with their clues.
+///
+/// This works like `generate`, but every placed `Word` in the resulting `Grid` carries the
+/// clue of the entry it was created from. Because clues travel with their answers through
+/// the whole search, the same answer may appear several times with different clues.
+///
+/// # Arguments
+///
+/// * `entries` - A slice of `PuzzleEntry` values representing the answers and clues
+///   to be used in the crossword puzzle.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(Some(Grid))` if a valid crossword puzzle grid is successfully generated.
+/// - `Ok(None)` if no valid grid can be generated from the given entries.
+/// - `Err(Error)` if an error occurs during the generation process (e.g.,
+///   invalid word segments).
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::{generate_with_clues, PuzzleEntry};
+///
+/// let entries = &[
+///     PuzzleEntry::new("LOREM", "Placeholder text opener"),
+///     PuzzleEntry::new("IPSUM", "Follows LOREM"),
+///     PuzzleEntry::new("DOLOR", "Pain, in Latin"),
+/// ];
+/// let grid = generate_with_clues(entries).unwrap().unwrap();
+/// for word in grid.words.iter() {
+///     let entry = entries
+///         .iter()
+///         .find(|e| e.answer == word.segment.full_word_str())
+///         .unwrap();
+///     assert_eq!(word.clue, entry.clue);
+/// }
+/// ```
+pub fn generate_with_clues<'a>(entries: &[PuzzleEntry<'a>]) -> Result<Option<Grid<'a>>, Error> {
+    generate_with(&GeneratorOptions::default(), entries)
+}
+
+/// Generates a crossword puzzle grid using the given `GeneratorOptions`.
+///
+/// This is the configurable entry point behind `generate`, `generate_with_clues` and
+/// `generate_with_max_size`. It accepts either plain answers (`&str`) or `PuzzleEntry`
+/// values carrying clues.
+///
+/// # Arguments
+///
+/// * `options` - The `GeneratorOptions` controlling retries, ordering and limits.
+/// * `entries` - A slice of answers or `PuzzleEntry` values to be used in the crossword puzzle.
+///
+/// # Returns
+///
+/// A `Result` which is:
+/// - `Ok(Some(Grid))` if a valid crossword puzzle grid is successfully generated.
+/// - `Ok(None)` if no valid grid can be generated from the given entries.
+/// - `Err(Error)` if an error occurs during the generation process (e.g.,
+///   invalid word segments).
+///
+/// # Errors
+///
+/// Returns an `Error::WordError` if any answer contains lowercase characters or whitespace.
+/// Answers taken from real word lists should first be passed through a
+/// `normalize::Normalizer`, or be given to `generate_normalized`, which does so.
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::generate_with;
+/// use crossword_puzzle::options::{GeneratorOptions, PlacementOrder, WordOrder};
+///
+/// let options = GeneratorOptions::new()
+///     .retries(5)
+///     .word_order(WordOrder::AsGiven)
+///     .placement_order(PlacementOrder::MostCrossings)
+///     .max_words(4)
+///     .max_size(10, 10);
+///
+/// let words = &["LOREM", "IPSUM", "DOLOR", "SIT", "AMET"];
+/// let grid = generate_with(&options, words).unwrap().unwrap();
+/// assert!(grid.words.len() <= 4);
+/// assert!(grid.board.len() <= 10 && grid.board[0].len() <= 10);
+///
+/// // A space would be read as an empty cell, so answers cannot contain one.
+/// assert!(generate_with(&options, &["ICE CREAM", "CREAM"]).is_err());
+/// ```
+pub fn generate_with<'a, E>(
+    options: &GeneratorOptions,
+    entries: &[E],
+) -> Result<Option<Grid<'a>>, Error>
+where
+    E: Into<PuzzleEntry<'a>> + Copy,
+{
+    generate_with_report(options, entries).map(|(grid, _)| grid)
+}
+
+/// Generates a crossword puzzle grid and reports which requested words were left out.
+///
+/// This works like `generate_with`, but also returns a `GenerationReport`. The report
+/// lists every requested word that does not appear in the grid together with a
+/// `DropReason`, and counts how many placements were tried and how often the search
+/// backtracked. Exact duplicate entries are only placed once and are not reported.
+///
+/// # Arguments
+///
+/// * `options` - The `GeneratorOptions` controlling retries, ordering and limits.
+/// * `entries` - A slice of answers or `PuzzleEntry` values to be used in the crossword puzzle.
+///
+/// # Returns
+///
+/// - `Ok((Option<Grid>, GenerationReport))` with the generated grid (if any) and the report.
+/// - `Err(Error)` if an error occurs during the generation process.
+///
+/// # Errors
+///
+/// Returns an `Error::WordError` if any answer contains lowercase characters or whitespace.
+/// Answers taken from real word lists should first be passed through a
+/// `normalize::Normalizer`, or be given to `generate_normalized`, which does so.
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::generate_with_report;
+/// use crossword_puzzle::options::GeneratorOptions;
+/// use crossword_puzzle::report::DropReason;
+///
+/// let words = &["LOREM", "IPSUM", "DOLOR", "SIT", "AMET", "XYZ"];
+/// let (grid, report) = generate_with_report(&GeneratorOptions::new(), words).unwrap();
+///
+/// assert_eq!(grid.unwrap().words.len(), 5);
+/// assert_eq!(report.unplaced.len(), 1);
+/// assert_eq!(report.unplaced[0].answer, "XYZ");
+/// assert_eq!(report.unplaced[0].reason, DropReason::NoSharedLetters);
+/// assert!(report.attempts >= 5);
+/// ```
+pub fn generate_with_report<'a, E>(
+    options: &GeneratorOptions,
+    entries: &[E],
+) -> Result<(Option<Grid<'a>>, GenerationReport<'a>), Error>
+where
+    E: Into<PuzzleEntry<'a>> + Copy,
+{
+    let initial_grid = Grid {
+        max_width: options.max_width,
+        max_height: options.max_height,
+        ..Grid::new()
+    };
+    generate_around(initial_grid, options, entries)
+}
+
+/// Generates a crossword puzzle grid from answers as they are written in a word list.
+///
+/// The answers are normalized with the `normalizer` of the options (see
+/// `normalize::Normalizer`) before the search, so they may be in lowercase and contain
+/// spaces, punctuation and accents. Answers that are empty after normalization are skipped.
+/// The grid then works like the one from `generate_with_report`, with the answers written
+/// as given kept as their display form.
+///
+/// The normalized answers are not part of `entries`, so the grid is returned as an
+/// `OwnedGrid`. The report lists the words left out as they were given in `entries`.
+///
+/// # Arguments
+///
+/// * `options` - The `GeneratorOptions` controlling normalization, retries, ordering and limits.
+/// * `entries` - The answers with their clues, as written in the word list.
+///
+/// # Returns
+///
+/// - `Ok((Option<OwnedGrid>, GenerationReport))` with the generated grid (if any) and the report.
+/// - `Err(Error)` if an error occurs during the generation process.
+///
+/// # Errors
+///
+/// Returns an `Error::WordError` if a normalized answer still contains lowercase characters.
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::generate_normalized;
+/// use crossword_puzzle::normalize::{Diacritics, Normalizer};
+/// use crossword_puzzle::options::GeneratorOptions;
+/// use crossword_puzzle::report::DropReason;
+///
+/// let options = GeneratorOptions::new().normalizer(Normalizer::new().diacritics(Diacritics::Strip));
+/// let entries = [
+///     ("ice cream", "Frozen dessert"),
+///     ("Crème brûlée", "Custard with a burnt top"),
+///     ("sorbet", "Fruity ice"),
+///     ("xyz", "Nothing to cross"),
+/// ];
+/// let (grid, report) = generate_normalized(&options, &entries).unwrap();
+///
+/// let grid = grid.unwrap();
+/// assert_eq!(grid.words.len(), 3);
+/// assert!(grid.words.iter().any(|word| word.display == "Crème brûlée"));
+/// assert_eq!(report.unplaced.len(), 1);
+/// assert_eq!(report.unplaced[0].answer, "xyz");
+/// assert_eq!(report.unplaced[0].reason, DropReason::NoSharedLetters);
+/// ```
+pub fn generate_normalized<'a>(
+    options: &GeneratorOptions,
+    entries: &[(&'a str, &'a str)],
+) -> Result<(Option<OwnedGrid>, GenerationReport<'a>), Error> {
+    let normalized: Vec<_> = entries
+        .iter()
+        .enumerate()
+        .map(|(index, (answer, clue))| (index, options.normalizer.normalize_entry(answer, clue)))
+        .filter(|(_, entry)| !entry.answer.trim().is_empty())
+        .collect();
+    let puzzle_entries: Vec<PuzzleEntry<'_>> =
+        normalized.iter().map(|(_, entry)| entry.into()).collect();
+    let (grid, report) = generate_with_report(options, &puzzle_entries)?;
+
+    let unplaced = report
+        .unplaced
+        .iter()
+        .filter_map(|word| {
+            let (index, _) = normalized
+                .iter()
+                .find(|(_, entry)| entry.answer == word.answer && entry.clue == word.clue)?;
+            let (answer, clue) = entries[*index];
+            Some(UnplacedWord {
+                answer,
+                clue,
+                reason: word.reason,
+            })
+        })
+        .collect();
+    let report = GenerationReport {
+        unplaced,
+        attempts: report.attempts,
+        backtracks: report.backtracks,
+        budget_exhausted: report.budget_exhausted,
+        islands: report.islands,
+    };
+    Ok((grid.map(OwnedGrid::from), report))
+}
+
+/// Generates a crossword puzzle grid around pre-placed anchor words.
+///
+/// The anchors are placed first with `Grid::from_anchors`, and the search then builds the
+/// rest of the puzzle around them, exactly like `generate_with_report` does from an empty
+/// grid. Anchors are never moved or removed, and entries whose answer is already placed
+/// as an anchor are skipped.
+///
+/// The options must limit both the width and the height of the board. The board starts at
+/// that size, so it cannot grow and every anchor keeps the exact cell it was given. Place a
+/// theme entry across the middle row of a 15×15 puzzle, for example, by anchoring it at
+/// row 7 and setting `max_size(15, 15)`.
+///
+/// # Arguments
+///
+/// * `options` - The `GeneratorOptions` controlling retries, ordering and limits.
+/// * `anchors` - The words to pre-place, with their `position` and `direction` set.
+/// * `entries` - A slice of answers or `PuzzleEntry` values to place around the anchors.
+///
+/// # Returns
+///
+/// - `Ok((Option<Grid>, GenerationReport))` with the generated grid (if any) and the report.
+/// - `Err(Error)` if the anchors cannot be placed or an error occurs during generation.
+///
+/// # Errors
+///
+/// Returns an `Error::GridError` if `Grid::from_anchors` rejects the anchors, if anchors
+/// are given without both a maximum width and height (`GridError::UnboundedAnchors`), or
+/// if an anchor extends beyond the board size limits (`GridError::OutOfBounds`). Returns
+/// an `Error::WordError` if any answer contains lowercase characters or whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::generate_with_anchors;
+/// use crossword_puzzle::options::GeneratorOptions;
+/// use crossword_puzzle::word::{Direction, Position, Word};
+///
+/// let theme = Word::value("", 'C', "ROSSWORD").unwrap().position(0, 4).direction(Direction::Horizontal);
+/// let options = GeneratorOptions::new().max_size(9, 9);
+/// let words = &["ROSE", "WORD", "SWORD", "CROSS", "DRAW"];
+///
+/// let (grid, _) = generate_with_anchors(&options, &[theme], words).unwrap();
+/// let grid = grid.unwrap();
+/// assert!(grid.words.len() > 1);
+/// assert_eq!(grid.board.len(), 9);
+/// assert_eq!(grid.words[0].origin, Position { x: 0, y: 4 });
+/// assert_eq!(grid.board[4].iter().collect::<String>(), "CROSSWORD");
+///
+/// // Without size limits the board could grow before the anchor and move it.
+/// assert!(generate_with_anchors(&GeneratorOptions::new(), &[theme], words).is_err());
+/// ```
+pub fn generate_with_anchors<'a, E>(
+    options: &GeneratorOptions,
+    anchors: &[Word<'a>],
+    entries: &[E],
+) -> Result<(Option<Grid<'a>>, GenerationReport<'a>), Error>
+where
+    E: Into<PuzzleEntry<'a>> + Copy,
+{
+    let mut grid = Grid::from_anchors(anchors)?;
+    if !grid.words.is_empty() {
+        let (Some(max_width), Some(max_height)) = (options.max_width, options.max_height) else {
+            return Err(Error::GridError(GridError::UnboundedAnchors));
+        };
+        for word in grid.words.iter() {
+            let last = *word.positions().last().unwrap_or(&word.origin);
+            if last.x >= max_width || last.y >= max_height {
+                return Err(Error::GridError(GridError::OutOfBounds {
+                    answer: word.segment.full_word_str(),
+                    position: last,
+                }));
+            }
+        }
+        for row in grid.board.iter_mut() {
+            row.resize(max_width, ' ');
+        }
+        grid.board.resize(max_height, vec![' '; max_width]);
+    }
+    grid.max_width = options.max_width;
+    grid.max_height = options.max_height;
+
+    generate_around(grid, options, entries)
+}
+
+/// Returns why `answer` has no valid placement on `grid`: `DropReason::NoValidCrossing` if
+/// it shares a letter with the board, so its crossings were blocked, or else
+/// `DropReason::NoLetterOnBoard`.
+fn crossing_failure(grid: &Grid<'_>, answer: &str) -> DropReason {
+    let shares_letter = answer
+        .chars()
+        .any(|ch| grid.board.iter().flatten().any(|board_ch| *board_ch == ch));
+    if shares_letter {
+        DropReason::NoValidCrossing
+    } else {
+        DropReason::NoLetterOnBoard
+    }
+}
+
+/// Checks that every answer can be placed on a grid. Answers must not contain lowercase
+/// characters, nor whitespace other than around the answer, as a space marks an empty cell.
+fn check_answers<'a>(answers: impl IntoIterator<Item = &'a str>) -> Result<(), WordError> {
+    for answer in answers {
+        if answer.chars().any(char::is_lowercase) {
+            return Err(WordError::LowercaseCharactersInSegment);
+        }
+        if answer.trim().chars().any(char::is_whitespace) {
+            return Err(WordError::WhitespaceInSegment);
+        }
+    }
+    Ok(())
+}
+
+/// Places the entries around the words already on `grid`, as described in
+/// `generate_with_report` and `generate_with_anchors`.
+fn generate_around<'a, E>(
+    grid: Grid<'a>,
+    options: &GeneratorOptions,
+    entries: &[E],
+) -> Result<(Option<Grid<'a>>, GenerationReport<'a>), Error>
+where
+    E: Into<PuzzleEntry<'a>> + Copy,
+{
+    let entries: Vec<PuzzleEntry<'a>> = entries.iter().map(|entry| (*entry).into()).collect();
+    check_answers(entries.iter().map(|entry| entry.answer))?;
+
+    // Entries placed as anchors are already on the grid. The remaining entries may also
+    // cross the letters of the anchors, so entries sharing a letter with the board are
+    // kept even if they share none with the other entries.
+    let anchored: Vec<String> = grid
+        .words
+        .iter()
+        .map(|word| word.segment.full_word_str())
+        .collect();
+    let entries: Vec<PuzzleEntry<'a>> = entries
+        .into_iter()
+        .filter(|entry| !anchored.iter().any(|answer| answer == entry.answer.trim()))
+        .collect();
+    let board_letters: HashSet<char> = grid
+        .board
+        .iter()
+        .flatten()
+        .copied()
+        .filter(|ch| *ch != ' ')
+        .collect();
+
+    let mut words_queue = eliminate_entries(&entries);
+    for entry in entries.iter() {
+        let is_queued = words_queue
+            .iter()
+            .any(|word| word.value == entry.answer && word.clue == entry.clue);
+        if !is_queued && entry.answer.chars().any(|ch| board_letters.contains(&ch)) {
+            words_queue.push_back(
+                PossibleWord::new(entry.answer)
+                    .clue(entry.clue)
+                    .display(entry.display),
+            );
+        }
+    }
+    sort_words(&mut words_queue, options.word_order);
+    for word in words_queue.iter_mut() {
+        word.remaining = options.retries.max(1);
+    }
+
+    let mut report = GenerationReport::default();
+    for (index, entry) in entries.iter().enumerate() {
+        let is_duplicate = entries[..index].contains(entry);
+        let is_queued = words_queue
+            .iter()
+            .any(|word| word.value == entry.answer && word.clue == entry.clue);
+        if !is_duplicate && !is_queued {
+            report.unplaced.push(UnplacedWord {
+                answer: entry.answer,
+                clue: entry.clue,
+                reason: DropReason::NoSharedLetters,
+            });
+        }
+    }
+
+    let grid = run_search(grid, words_queue, options, &mut report)?;
+    Ok((grid, report))
+}
+
+/// The number of bridge placements tried when no required word can be placed, see
+/// `generate_with_required`.
+const BRIDGE_CANDIDATES: usize = 8;
+
+/// A candidate placement in the search of `generate_with_required`.
+#[derive(Clone, Copy)]
+struct Candidate<'a> {
+    /// Whether the word is a required entry, or an optional entry used as a bridge.
+    required: bool,
+    /// The index of the entry in its list.
+    index: usize,
+    /// The placement of the word.
+    word: Word<'a>,
+}
+
+/// A level of the explicit search stack used by `generate_with_required`.
+struct RequiredFrame<'a> {
+    /// The candidate placements of this level, in the order they are tried.
+    candidates: Vec<Candidate<'a>>,
+    /// The index of the next candidate to try.
+    next: usize,
+    /// The candidate currently applied to the grid and the record to undo it.
+    applied: Option<(Candidate<'a>, PlacementRecord)>,
+}
+
+/// Returns the valid placements of `answer` on `grid` that cover distinct cells, ordered
+/// by the `placement_order` of the options after an optional seeded shuffle.
+fn distinct_placements<'a>(
+    grid: &Grid<'a>,
+    answer: &'a str,
+    options: &GeneratorOptions,
+    rng: &mut Option<Rng>,
+) -> Result<Vec<Word<'a>>, GridError> {
+    let mut placements = grid.find_valid_placements(answer)?;
+    let mut starts: Vec<(isize, isize, Direction)> = Vec::new();
+    placements.retain(|word| {
+        let prefix = word.segment.prefix_len() as isize;
+        let (x, y) = (word.position.x as isize, word.position.y as isize);
+        let start = match word.direction {
+            Direction::Vertical => (x, y - prefix, word.direction),
+            _ => (x - prefix, y, word.direction),
+        };
+        let is_new = !starts.contains(&start);
+        starts.push(start);
+        is_new
+    });
+    if let Some(rng) = rng.as_mut() {
+        rng.shuffle(&mut placements);
+    }
+    sort_placements(grid, &mut placements, options.placement_order);
+    Ok(placements)
+}
+
+/// Type alias for the ranking of a placement in `fill_optional`: the number of crossings,
+/// the reversed area of the board after the placement, the length of the word, and the
+/// reversed squared distance to the centre of the board.
+type FillKey = (
+    usize,
+    std::cmp::Reverse<isize>,
+    usize,
+    std::cmp::Reverse<i32>,
+);
+
+/// Adds optional entries to `grid` one at a time until no entry fits or the `max_words`
+/// limit of the options is reached. Each round places the entry and placement with the
+/// most crossings, preferring placements that grow the board least, then longer words,
+/// then cells closer to the centre. Ties keep the order of `entries`.
+fn fill_optional<'a>(
+    grid: &mut Grid<'a>,
+    entries: &[PuzzleEntry<'a>],
+    used: &mut [bool],
+    options: &GeneratorOptions,
+    report: &mut GenerationReport<'a>,
+) -> Result<(), Error> {
+    while options
+        .max_words
+        .is_none_or(|max_words| grid.words.len() < max_words)
+    {
+        let centre = Position {
+            x: grid.board[0].len() / 2,
+            y: grid.board.len() / 2,
+        };
+        let mut best: Option<(usize, Word<'a>, FillKey)> = None;
+        for (index, entry) in entries.iter().enumerate() {
+            if used[index] {
+                continue;
+            }
+            for word in grid.find_valid_placements(entry.answer)? {
+                let (width, height) = grid.grown_size(&word);
+                let key = (
+                    grid.count_crossings(&word),
+                    std::cmp::Reverse(width * height),
+                    entry.answer.chars().count(),
+                    std::cmp::Reverse(squared_euclidean(word.position, centre)),
+                );
+                if best.as_ref().is_none_or(|(_, _, best_key)| key > *best_key) {
+                    best = Some((index, word.clue(entry.clue).display(entry.display), key));
+                }
+            }
+        }
+
+        let Some((index, word, _)) = best else {
+            break;
+        };
+        grid.place_word(word)?;
+        used[index] = true;
+        report.attempts += 1;
+    }
+    Ok(())
+}
+
+/// Generates a crossword puzzle grid that contains every required word, filled up with
+/// optional words.
+///
+/// Unlike `generate_with_report`, which may leave out any word, this search treats the
+/// two lists differently:
+/// - Every `required` entry must be placed. The search backtracks over the placements of
+///   the required words, always placing the required word with the fewest valid
+///   placements next. When no remaining required word fits, an optional word sharing a
+///   letter with one of them may be placed as a bridge, trying the placements with the
+///   most crossings first, but never two bridges in a row.
+/// - Once all required words are placed, optional entries are added one at a time, each
+///   time choosing the placement with the most crossings that grows the board least, until
+///   none fits or the grid holds `max_words` words. The `max_words` limit is thus the
+///   target word count of the puzzle.
+///
+/// The search keeps going after the first complete layout of the required words: up to
+/// `layouts` layouts are filled with optional words, and the one with the best
+/// `LayoutScore` is returned. The `timeout` and `max_nodes` budgets bound the search; when
+/// they run out, the best layout found so far is returned.
+///
+/// Of the ordering options, only `placement_order` and the `seed` apply: the order of the
+/// words follows the rules above, so `word_order` and `retries` are not used. Every word
+/// after the first crosses a word already placed, so the grid is always connected and
+/// `require_connected` holds without being checked.
+///
+/// # Arguments
+///
+/// * `options` - The `GeneratorOptions` controlling layouts, ordering, limits and budgets.
+/// * `required` - The answers or `PuzzleEntry` values that must appear in the puzzle.
+/// * `optional` - The answers or `PuzzleEntry` values that may be used to fill the puzzle.
+///
+/// # Returns
+///
+/// - `Ok((Some(Grid), GenerationReport))` with a grid holding every required word. The
+///   report lists the optional words that were not used, as `DropReason::WordLimitReached`
+///   if the grid holds `max_words` words, and otherwise as `DropReason::NoValidCrossing` or
+///   `DropReason::NoLetterOnBoard`.
+/// - `Ok((None, GenerationReport))` if the required words cannot all be placed. If a
+///   required word shares no letter with any other word, the search does not start, and
+///   the report lists such words as `DropReason::NoSharedLetters`. Otherwise, it lists the
+///   required words that had no valid placement on the most complete layout that was found,
+///   or, if the budget ran out, every required word missing from it.
+/// - `Err(Error)` if an error occurs during the generation process.
+///
+/// # Errors
+///
+/// Returns an `Error::WordError` if any answer contains lowercase characters or whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::generate_with_required;
+/// use crossword_puzzle::options::GeneratorOptions;
+/// use crossword_puzzle::report::DropReason;
+///
+/// let theme = &["RUST", "CARGO", "CRATE", "BORROW"];
+/// let filler = &["TRAIT", "MACRO", "TOKIO", "SERDE", "ENUM", "TRAIN", "ORBIT", "ERROR", "ACORN"];
+/// let options = GeneratorOptions::new().max_words(8).layouts(5);
+///
+/// let (grid, report) = generate_with_required(&options, theme, filler).unwrap();
+/// let grid = grid.unwrap();
+/// let answers: Vec<String> = grid.words.iter().map(|w| w.segment.full_word_str()).collect();
+/// assert!(theme.iter().all(|word| answers.iter().any(|answer| answer == word)));
+/// assert_eq!(grid.words.len(), 8);
+/// assert_eq!(report.unplaced.len(), filler.len() + theme.len() - 8);
+/// assert!(report.unplaced.iter().all(|w| w.reason == DropReason::WordLimitReached));
+/// assert!(grid.validate().is_ok() && grid.is_connected());
+///
+/// // "QXZJ" can never cross another word, so no puzzle holds every theme word.
+/// let (grid, report) = generate_with_required(&options, &["RUST", "CARGO", "QXZJ"], filler).unwrap();
+/// assert!(grid.is_none());
+/// assert_eq!(report.unplaced.len(), 1);
+/// assert_eq!(report.unplaced[0].answer, "QXZJ");
+/// assert_eq!(report.unplaced[0].reason, DropReason::NoSharedLetters);
+/// ```
+pub fn generate_with_required<'a, E>(
+    options: &GeneratorOptions,
+    required: &[E],
+    optional: &[E],
+) -> Result<(Option<Grid<'a>>, GenerationReport<'a>), Error>
+where
+    E: Into<PuzzleEntry<'a>> + Copy,
+{
+    let mut required_entries: Vec<PuzzleEntry<'a>> = Vec::new();
+    for entry in required.iter().map(|entry| (*entry).into()) {
+        if !required_entries.contains(&entry) {
+            required_entries.push(entry);
+        }
+    }
+    let mut optional_entries: Vec<PuzzleEntry<'a>> = Vec::new();
+    for entry in optional.iter().map(|entry| (*entry).into()) {
+        let is_required = required_entries
+            .iter()
+            .any(|required| required.answer == entry.answer);
+        if !is_required && !optional_entries.contains(&entry) {
+            optional_entries.push(entry);
+        }
+    }
+    check_answers(
+        required_entries
+            .iter()
+            .chain(optional_entries.iter())
+            .map(|entry| entry.answer),
+    )?;
+
+    // A required word that shares no letter with any other word can never cross one, so
+    // no layout can hold it together with the other required words.
+    let mut report = GenerationReport::default();
+    if required_entries.len() > 1 {
+        report.unplaced = required_entries
+            .iter()
+            .enumerate()
+            .filter(|(index, entry)| {
+                !required_entries
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, _)| other != index)
+                    .map(|(_, other)| other)
+                    .chain(optional_entries.iter())
+                    .any(|other| other.answer.chars().any(|ch| entry.answer.contains(ch)))
+            })
+            .map(|(_, entry)| UnplacedWord {
+                answer: entry.answer,
+                clue: entry.clue,
+                reason: DropReason::NoSharedLetters,
+            })
+            .collect();
+        if !report.unplaced.is_empty() {
+            return Ok((None, report));
+        }
+    }
+
+    let mut rng = options.seed.map(Rng::new);
+    if let Some(rng) = rng.as_mut() {
+        rng.shuffle(&mut optional_entries);
+    }
+
+    let mut grid = Grid {
+        max_width: options.max_width,
+        max_height: options.max_height,
+        ..Grid::new()
+    };
+    let mut placed = vec![false; required_entries.len()];
+    let mut used = vec![false; optional_entries.len()];
+    let mut stack: Vec<RequiredFrame<'a>> = Vec::new();
+    let mut best: Option<(Grid<'a>, Vec<bool>)> = None;
+    let mut best_partial = (placed.clone(), grid.clone());
+    let mut layouts = 0;
+    let started = Instant::now();
+
+    loop {
+        let out_of_time = options
+            .timeout
+            .is_some_and(|timeout| started.elapsed() >= timeout);
+        let out_of_nodes = options
+            .max_nodes
+            .is_some_and(|max_nodes| report.attempts >= max_nodes);
+        if out_of_time || out_of_nodes {
+            report.budget_exhausted = true;
+            break;
+        }
+
+        if placed.iter().all(|placed| *placed) {
+            let mut filled = grid.clone();
+            let mut filled_used = used.clone();
+            fill_optional(
+                &mut filled,
+                &optional_entries,
+                &mut filled_used,
+                options,
+                &mut report,
+            )?;
+            if best
+                .as_ref()
+                .is_none_or(|(best_grid, _)| filled.score() > best_grid.score())
+            {
+                best = Some((filled, filled_used));
+            }
+            layouts += 1;
+            if layouts >= options.layouts.max(1) || stack.is_empty() {
+                break;
+            }
+        } else {
+            let mut chosen: Option<(usize, Vec<Word<'a>>)> = None;
+            for (index, entry) in required_entries.iter().enumerate() {
+                if placed[index] {
+                    continue;
+                }
+                let placements = distinct_placements(&grid, entry.answer, options, &mut rng)?;
+                if placements.is_empty() {
+                    continue;
+                }
+                let better = chosen
+                    .as_ref()
+                    .is_none_or(|(chosen_index, chosen_placements)| {
+                        placements.len() < chosen_placements.len()
+                            || (placements.len() == chosen_placements.len()
+                                && entry.answer.chars().count()
+                                    > required_entries[*chosen_index].answer.chars().count())
+                    });
+                if better {
+                    chosen = Some((index, placements));
+                }
+            }
+
+            let missing = placed.iter().filter(|placed| !**placed).count();
+            let after_bridge = stack
+                .last()
+                .and_then(|frame| frame.applied.as_ref())
+                .is_some_and(|(candidate, _)| !candidate.required);
+            let mut candidates = Vec::new();
+            match chosen {
+                Some((index, placements)) => {
+                    let entry = required_entries[index];
+                    candidates.extend(placements.into_iter().map(|word| Candidate {
+                        required: true,
+                        index,
+                        word: word.clue(entry.clue).display(entry.display),
+                    }));
+                }
+                None if !after_bridge
+                    && !grid.words.is_empty()
+                    && options
+                        .max_words
+                        .is_none_or(|max_words| grid.words.len() + missing < max_words) =>
+                {
+                    let letters: HashSet<char> = required_entries
+                        .iter()
+                        .zip(placed.iter())
+                        .filter(|(_, placed)| !**placed)
+                        .flat_map(|(entry, _)| entry.answer.chars())
+                        .collect();
+                    for (index, entry) in optional_entries.iter().enumerate() {
+                        if used[index] || !entry.answer.chars().any(|ch| letters.contains(&ch)) {
+                            continue;
+                        }
+                        for word in distinct_placements(&grid, entry.answer, options, &mut rng)? {
+                            candidates.push(Candidate {
+                                required: false,
+                                index,
+                                word: word.clue(entry.clue).display(entry.display),
+                            });
+                        }
+                    }
+                    candidates.sort_by_key(|candidate| {
+                        std::cmp::Reverse(grid.count_crossings(&candidate.word))
+                    });
+                    candidates.truncate(BRIDGE_CANDIDATES);
+                }
+                None => {}
+            }
+            stack.push(RequiredFrame {
+                candidates,
+                next: 0,
+                applied: None,
+            });
+        }
+
+        // Apply the next untried candidate of the innermost frame. A frame whose
+        // candidates are all used up is a dead end: it is removed and the search resumes
+        // with the next candidate of the frame before it.
+        let mut exhausted = false;
+        loop {
+            let Some(frame) = stack.last_mut() else {
+                exhausted = true;
+                break;
+            };
+
+            if let Some((candidate, record)) = frame.applied.take() {
+                grid.undo_placement(record);
+                if candidate.required {
+                    placed[candidate.index] = false;
+                } else {
+                    used[candidate.index] = false;
+                }
+            }
+
+            if frame.next < frame.candidates.len() {
+                if frame.next > 0 {
+                    report.backtracks += 1;
+                }
+                let candidate = frame.candidates[frame.next];
+                frame.next += 1;
+                let record = grid.place_word(candidate.word)?;
+                if candidate.required {
+                    placed[candidate.index] = true;
+                } else {
+                    used[candidate.index] = true;
+                }
+                frame.applied = Some((candidate, record));
+                report.attempts += 1;
+
+                let count = |flags: &[bool]| flags.iter().filter(|flag| **flag).count();
+                if count(&placed) > count(&best_partial.0) {
+                    best_partial = (placed.clone(), grid.clone());
+                }
+                break;
+            }
+
+            stack.pop();
+        }
+        if exhausted {
+            break;
+        }
+    }
+
+    let Some((grid, used)) = best else {
+        // The required words that still fit on the most complete layout were simply not
+        // reached, so only those without a valid placement there are reported.
+        let (partial_placed, partial_grid) = best_partial;
+        for (entry, placed) in required_entries.iter().zip(partial_placed.iter()) {
+            if *placed {
+                continue;
+            }
+            let reason = if report.budget_exhausted {
+                DropReason::BudgetExhausted
+            } else if partial_grid.find_valid_placements(entry.answer)?.is_empty() {
+                crossing_failure(&partial_grid, entry.answer)
+            } else {
+                continue;
+            };
+            report.unplaced.push(UnplacedWord {
+                answer: entry.answer,
+                clue: entry.clue,
+                reason,
+            });
+        }
+        return Ok((None, report));
+    };
+
+    let limit_reached = options
+        .max_words
+        .is_some_and(|max_words| grid.words.len() >= max_words);
+    report.unplaced = optional_entries
+        .iter()
+        .zip(used.iter())
+        .filter(|(_, used)| !**used)
+        .map(|(entry, _)| UnplacedWord {
+            answer: entry.answer,
+            clue: entry.clue,
+            reason: if limit_reached {
+                DropReason::WordLimitReached
+            } else {
+                crossing_failure(&grid, entry.answer)
+            },
+        })
+        .collect();
+    Ok((Some(grid), report))
+}
+
+/// Generates a crossword puzzle grid from the best subset of a large pool of words.
+///
+/// Unlike `generate_with_report`, which tries to place every word it is given, this
+/// generator chooses which words to use as well as where to place them. It suits pools
+/// far larger than the puzzle, such as a whole dictionary:
+/// - The pool is shuffled with the seed of the options, if any, and the longest words
+///   that fit within `max_width` and `max_height` are taken as start words, one per layout.
+/// - Each layout places its start word and then adds pool words one at a time, each time
+///   choosing the placement with the most crossings that grows the board least, until no
+///   word fits or the grid holds `max_words` words.
+/// - Up to `layouts` layouts are built this way, and the one with the best `LayoutScore`
+///   is returned: the most words, then the most crossings, then the densest board.
+///
+/// Without `max_words`, every layout grows until no pool word fits anymore, so a target
+/// word count should usually be set. The `timeout` and `max_nodes` budgets are checked
+/// before each layout; when they run out, the best layout found so far is returned. As in
+/// `generate_with_required`, `word_order` and `retries` are not used, and the grid is
+/// always connected.
+///
+/// # Arguments
+///
+/// * `options` - The `GeneratorOptions` controlling layouts, the seed, limits and budgets.
+/// * `pool` - The answers or `PuzzleEntry` values the puzzle may be built from.
+///
+/// # Returns
+///
+/// - `Ok((Some(Grid), GenerationReport))` with the best layout found. The report lists the
+///   pool words that were not used.
+/// - `Ok((None, GenerationReport))` if no word of the pool fits within the size limits, or
+///   the budget ran out before the first layout.
+/// - `Err(Error)` if an error occurs during the generation process.
+///
+/// # Errors
+///
+/// Returns an `Error::WordError` if any answer contains lowercase characters or whitespace.
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::generate_from_pool;
+/// use crossword_puzzle::options::GeneratorOptions;
+///
+/// let pool = [
+///     "RUST", "CARGO", "CRATE", "TRAIT", "MACRO", "BORROW", "ENUM", "STRUCT", "TOKEN", "ARRAY",
+///     "VECTOR", "SLICE", "MATCH", "CLOSURE", "ITER", "MODULE", "SERDE", "TOKIO", "ASYNC", "AWAIT",
+/// ];
+/// let options = GeneratorOptions::new().max_size(9, 9).max_words(8);
+///
+/// let (grid, report) = generate_from_pool(&options, &pool).unwrap();
+/// let grid = grid.unwrap();
+/// assert_eq!(grid.words.len(), 8);
+/// assert!(grid.board.len() <= 9 && grid.board[0].len() <= 9);
+/// assert!(grid.validate().is_ok() && grid.is_connected());
+/// assert_eq!(report.unplaced.len(), pool.len() - 8);
+/// ```
+pub fn generate_from_pool<'a, E>(
+    options: &GeneratorOptions,
+    pool: &[E],
+) -> Result<(Option<Grid<'a>>, GenerationReport<'a>), Error>
+where
+    E: Into<PuzzleEntry<'a>> + Copy,
+{
+    let mut entries: Vec<PuzzleEntry<'a>> = Vec::new();
+    for entry in pool.iter().map(|entry| (*entry).into()) {
+        if !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+    check_answers(entries.iter().map(|entry| entry.answer))?;
+
+    let mut rng = options.seed.map(Rng::new);
+    if let Some(rng) = rng.as_mut() {
+        rng.shuffle(&mut entries);
+    }
+    let empty = Grid {
+        max_width: options.max_width,
+        max_height: options.max_height,
+        ..Grid::new()
+    };
+    let mut starts: Vec<(usize, Word<'a>)> = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        if let Some(word) = distinct_placements(&empty, entry.answer, options, &mut None)?
+            .into_iter()
+            .next()
+        {
+            starts.push((index, word.clue(entry.clue).display(entry.display)));
+        }
+    }
+    starts.sort_by_key(|(index, _)| std::cmp::Reverse(entries[*index].answer.chars().count()));
+    starts.truncate(options.layouts.max(1));
+
+    let mut report = GenerationReport::default();
+    let mut best: Option<(Layout<'a>, Vec<bool>)> = None;
+    let started = Instant::now();
+    for (index, word) in starts {
+        let out_of_time = options
+            .timeout
+            .is_some_and(|timeout| started.elapsed() >= timeout);
+        let out_of_nodes = options
+            .max_nodes
+            .is_some_and(|max_nodes| report.attempts >= max_nodes);
+        if out_of_time || out_of_nodes {
+            report.budget_exhausted = true;
+            break;
+        }
+
+        let mut grid = empty.clone();
+        let mut used = vec![false; entries.len()];
+        grid.place_word(word)?;
+        used[index] = true;
+        report.attempts += 1;
+        fill_optional(&mut grid, &entries, &mut used, options, &mut report)?;
+
+        let layout = Layout::from(grid);
+        if best
+            .as_ref()
+            .is_none_or(|(best_layout, _)| layout.score > best_layout.score)
+        {
+            best = Some((layout, used));
+        }
+    }
+
+    let Some((layout, used)) = best else {
+        return Ok((None, report));
+    };
+    let grid = layout.grid;
+    let limit_reached = options
+        .max_words
+        .is_some_and(|max_words| grid.words.len() >= max_words);
+    report.unplaced = entries
+        .iter()
+        .zip(used.iter())
+        .filter(|(_, used)| !**used)
+        .map(|(entry, _)| UnplacedWord {
+            answer: entry.answer,
+            clue: entry.clue,
+            reason: if limit_reached {
+                DropReason::WordLimitReached
+            } else {
+                crossing_failure(&grid, entry.answer)
+            },
+        })
+        .collect();
+    Ok((Some(grid), report))
+}
+
+/// Generates up to `count` distinct layouts of the same word list, ranked by quality.
+///
+/// The first candidate is the layout `generate_with` produces for `options`. Further
+/// candidates are searched with successive seeds (starting from the seed in `options`,
+/// or `0` if none is set), and only layouts whose board differs from every layout found
+/// so far are kept. At most `count * 4` searches are run, so fewer than `count` layouts
+/// are returned when the word list does not allow that many distinct arrangements.
+///
+/// Every layout is scored with `Grid::score`, and the result is sorted from the best
+/// score to the worst.
+///
+/// # Arguments
+///
+/// * `options` - The `GeneratorOptions` used for every search.
+/// * `entries` - A slice of answers or `PuzzleEntry` values to be used in the crossword puzzle.
+/// * `count` - The maximum number of layouts to return.
+///
+/// # Returns
+///
+/// - `Ok(Vec<Layout>)` containing the distinct layouts found, best first.
+/// - `Err(Error)` if an error occurs during the generation process.
+///
+/// # Errors
+///
+/// Returns an `Error` if `generate_with` returns an error.
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::generate_layouts;
+/// use crossword_puzzle::options::GeneratorOptions;
+///
+/// let words = &["LOREM", "IPSUM", "DOLOR", "SIT", "AMET"];
+/// let layouts = generate_layouts(&GeneratorOptions::new(), words, 3).unwrap();
+///
+/// assert!(!layouts.is_empty() && layouts.len() <= 3);
+/// for pair in layouts.windows(2) {
+///     assert!(pair[0].score >= pair[1].score);
+///     assert_ne!(pair[0].grid.board, pair[1].grid.board);
+/// }
+/// ```
+pub fn generate_layouts<'a, E>(
+    options: &GeneratorOptions,
+    entries: &[E],
+    count: usize,
+) -> Result<Vec<Layout<'a>>, Error>
+where
+    E: Into<PuzzleEntry<'a>> + Copy,
+{
+    let mut layouts: Vec<Layout<'a>> = Vec::new();
+    let base_seed = options.seed.unwrap_or(0);
+
+    for attempt in 0..count.saturating_mul(4) {
+        if layouts.len() >= count {
+            break;
+        }
+
+        let attempt_options = if attempt == 0 {
+            options.clone()
+        } else {
+            options.clone().seed(base_seed.wrapping_add(attempt as u64))
+        };
+
+        if let Some(grid) = generate_with(&attempt_options, entries)? {
+            if layouts.iter().all(|layout| layout.grid.board != grid.board) {
+                layouts.push(Layout::from(grid));
+            }
         }
     }
 
-    let words_queue = eliminate_words(words);
-    let initial_grid = Grid::new();
-    backtrack(initial_grid, words_queue)
+    layouts.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    Ok(layouts)
 }
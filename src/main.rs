@@ -1,35 +1,501 @@
 //! This is the main executable for the crossword puzzle generator.
-//! It takes a list of words as command-line arguments and attempts to generate
-//! a crossword puzzle from them.
+//!
+//! It generates crossword puzzles from word lists and converts saved puzzles between the
+//! supported output formats. Run `crossword-puzzle help` for the list of subcommands and
+//! flags. For compatibility, words given directly as arguments (`crossword-puzzle LOREM
+//! IPSUM ...`) are generated and printed as text. Such words are written in capitals, so a
+//! first argument with lowercase letters that names no subcommand is rejected.
 
-use crossword_puzzle::generate;
 use std::env;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+use std::str::FromStr;
 
-/// The main function of the crossword puzzle generator.
-/// It parses command-line arguments, calls the `generate` function from the
-/// `crossword_puzzle` crate, and prints the resulting crossword grid or an error message.
-fn main() {
-    let args: Vec<String> = env::args().collect();
+use crossword_puzzle::error::Error;
+use crossword_puzzle::metadata::Metadata;
+use crossword_puzzle::normalize::{Diacritics, Locale, NormalizedEntry};
+use crossword_puzzle::options::GeneratorOptions;
+use crossword_puzzle::owned::OwnedGrid;
+use crossword_puzzle::pdf::PdfOptions;
+use crossword_puzzle::svg::SvgOptions;
+use crossword_puzzle::{generate_from_pool, generate_normalized, Grid};
+
+/// The usage text printed by `help` and on invalid arguments.
+const USAGE: &str = "\
+Usage:
+  crossword-puzzle generate [FILE] [OPTIONS]   Generate a puzzle from a word list
+  crossword-puzzle render PUZZLE [OPTIONS]     Convert a saved puzzle to another format
+  crossword-puzzle validate PUZZLE             Check that a saved puzzle is consistent and connected
+  crossword-puzzle WORD...                     Generate a puzzle from the given words, in capitals
+  crossword-puzzle help                        Show this message
+
+The word list has one entry per line, either `ANSWER` or `ANSWER;clue`. Empty lines and
+lines starting with `#` are skipped. Without FILE, or with `-`, it is read from stdin.
+Answers are upper-cased, and their spaces and punctuation are removed.
+Saved puzzles are read as json, puz or ipuz, guessed from the file extension.
+
+Options:
+  -f, --format FORMAT      Output format: text, json, svg, puz, ipuz, html or pdf (default: text)
+  -o, --output PATH        Write the output to PATH instead of stdout
+      --input-format FMT   Format of the saved puzzle: json, puz or ipuz
+      --max-width N        Limit the board to N columns
+      --max-height N       Limit the board to N rows
+      --max-words N        Place at most N words
+      --seed N             Randomise the layout reproducibly with seed N
+      --retries N          Attempts made to place each word (default: 3)
+      --connected          Fail instead of returning a puzzle that falls apart into islands
+      --pool               Pick the best subset of the word list, up to --max-words words
+      --layouts N          Layouts compared by --pool before keeping the best (default: 3)
+      --locale LOCALE      Casing rules for answers: default or tr (default: default)
+      --strip-diacritics   Replace accented letters in answers with their base letter
+      --enumerate          Append the enumeration of multi-word answers to their clue
+      --solution           Show the solution in svg output and add an answer key to pdf output
+      --title TEXT         Title stored in the puzzle
+      --author TEXT        Author stored in the puzzle
+      --copyright TEXT     Copyright notice stored in the puzzle
+";
+
+/// `Format` is an input or output format of the command-line tool.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    /// The board followed by the numbered clue lists.
+    Text,
+    /// The JSON form of `Grid`.
+    Json,
+    /// An SVG image of the grid.
+    Svg,
+    /// An Across Lite `.puz` file.
+    Puz,
+    /// An ipuz document.
+    Ipuz,
+    /// A self-contained HTML player.
+    Html,
+    /// A printable PDF worksheet.
+    Pdf,
+}
+
+impl Format {
+    /// Returns the name of the format, as accepted by `--format`.
+    fn name(self) -> &'static str {
+        match self {
+            Format::Text => "text",
+            Format::Json => "json",
+            Format::Svg => "svg",
+            Format::Puz => "puz",
+            Format::Ipuz => "ipuz",
+            Format::Html => "html",
+            Format::Pdf => "pdf",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "text" | "txt" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "svg" => Ok(Format::Svg),
+            "puz" => Ok(Format::Puz),
+            "ipuz" => Ok(Format::Ipuz),
+            "html" | "htm" => Ok(Format::Html),
+            "pdf" => Ok(Format::Pdf),
+            _ => Err(Error::Custom(format!("unknown format `{name}`"))),
+        }
+    }
+}
+
+/// `Args` holds the parsed command-line arguments that follow the subcommand.
+#[derive(Debug, Default)]
+struct Args {
+    /// The arguments that are not flags.
+    positional: Vec<String>,
+    /// The output format, or `None` for text.
+    format: Option<Format>,
+    /// The output path, or `None` for stdout.
+    output: Option<String>,
+    /// The format of the saved puzzle, or `None` to guess it from the file extension.
+    input_format: Option<Format>,
+    /// The generator options built from the flags.
+    options: GeneratorOptions,
+    /// The metadata built from the flags.
+    metadata: Metadata,
+    /// Whether the solution is shown in svg and pdf output.
+    solution: bool,
+    /// Whether the word list is a pool to pick the puzzle words from.
+    pool: bool,
+}
+
+/// Parses the value of a numeric flag.
+fn parse_number<T: FromStr>(flag: &str, value: &str) -> Result<T, Error> {
+    value
+        .parse()
+        .map_err(|_| Error::Custom(format!("{flag} expects a number, got `{value}`")))
+}
 
-    if args.len() < 2 {
-        eprintln!("Usage: {} <word1> <word2> ...", args[0]);
-        return;
+/// Parses the value of `--locale`.
+fn parse_locale(value: &str) -> Result<Locale, Error> {
+    match value.to_ascii_lowercase().as_str() {
+        "default" => Ok(Locale::Default),
+        "tr" | "az" | "turkish" => Ok(Locale::Turkish),
+        _ => Err(Error::Custom(format!("unknown locale `{value}`"))),
     }
+}
 
-    let words: Vec<&str> = args[1..].iter().map(|s| s.as_str()).collect();
+impl Args {
+    /// Parses the arguments that follow the subcommand. Flags may be given as
+    /// `--flag value` or `--flag=value`.
+    fn parse(args: &[String]) -> Result<Self, Error> {
+        let mut parsed = Args::default();
+        let mut iter = args.iter();
 
-    match generate(&words) {
-        Ok(Some(grid)) => {
-            println!("Generated Crossword Puzzle:");
-            for row in grid.board.iter() {
-                println!("{}", row.iter().collect::<String>());
+        while let Some(arg) = iter.next() {
+            if !arg.starts_with('-') || arg == "-" {
+                parsed.positional.push(arg.clone());
+                continue;
+            }
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => (flag, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            match flag {
+                "--solution" => {
+                    parsed.solution = true;
+                    continue;
+                }
+                "--strip-diacritics" => {
+                    parsed.options.normalizer =
+                        parsed.options.normalizer.diacritics(Diacritics::Strip);
+                    continue;
+                }
+                "--connected" => {
+                    parsed.options = parsed.options.require_connected(true);
+                    continue;
+                }
+                "--pool" => {
+                    parsed.pool = true;
+                    continue;
+                }
+                "--enumerate" => {
+                    parsed.options.normalizer = parsed.options.normalizer.append_enumeration(true);
+                    continue;
+                }
+                _ => {}
             }
+
+            let value = match inline.or_else(|| iter.next().cloned()) {
+                Some(value) => value,
+                None => return Err(Error::Custom(format!("{flag} expects a value"))),
+            };
+            match flag {
+                "-f" | "--format" => parsed.format = Some(value.parse()?),
+                "-o" | "--output" => parsed.output = Some(value),
+                "--input-format" => parsed.input_format = Some(value.parse()?),
+                "--max-width" => parsed.options.max_width = Some(parse_number(flag, &value)?),
+                "--max-height" => parsed.options.max_height = Some(parse_number(flag, &value)?),
+                "--max-words" => {
+                    parsed.options = parsed.options.max_words(parse_number(flag, &value)?)
+                }
+                "--seed" => parsed.options = parsed.options.seed(parse_number(flag, &value)?),
+                "--retries" => parsed.options = parsed.options.retries(parse_number(flag, &value)?),
+                "--layouts" => parsed.options = parsed.options.layouts(parse_number(flag, &value)?),
+                "--locale" => {
+                    parsed.options.normalizer =
+                        parsed.options.normalizer.locale(parse_locale(&value)?)
+                }
+                "--title" => parsed.metadata.title = value,
+                "--author" => parsed.metadata.author = value,
+                "--copyright" => parsed.metadata.copyright = value,
+                _ => return Err(Error::Custom(format!("unknown flag `{flag}`"))),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// Writes `bytes` to the output path, or to stdout.
+    fn write_output(&self, bytes: &[u8]) -> Result<(), Error> {
+        let result = match &self.output {
+            Some(path) => fs::write(path, bytes),
+            None => io::stdout().write_all(bytes),
+        };
+        result.map_err(|err| Error::Custom(format!("cannot write output: {err}")))
+    }
+}
+
+/// Reads a word list, one `ANSWER` or `ANSWER;clue` entry per line, from a file or stdin.
+/// Answers and clues are trimmed; answers are normalized later.
+fn read_entries(path: Option<&str>) -> Result<Vec<(String, String)>, Error> {
+    let text = match path {
+        None | Some("-") => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|err| Error::Custom(format!("cannot read stdin: {err}")))?;
+            text
+        }
+        Some(path) => fs::read_to_string(path)
+            .map_err(|err| Error::Custom(format!("cannot read {path}: {err}")))?,
+    };
+
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (answer, clue) = line.split_once(';').unwrap_or((line, ""));
+            (answer.trim().to_string(), clue.trim().to_string())
+        })
+        .filter(|(answer, _)| !answer.is_empty())
+        .collect())
+}
+
+/// Reads a saved puzzle in the given format, or in the format named by its extension.
+fn read_puzzle(path: &str, format: Option<Format>) -> Result<(OwnedGrid, Metadata), Error> {
+    let format = match format {
+        Some(format) => format,
+        None => path
+            .rsplit_once('.')
+            .and_then(|(_, extension)| extension.parse().ok())
+            .ok_or_else(|| {
+                Error::Custom(format!(
+                    "cannot guess the format of {path}, use --input-format"
+                ))
+            })?,
+    };
+    let bytes =
+        fs::read(path).map_err(|err| Error::Custom(format!("cannot read {path}: {err}")))?;
+    let text = || {
+        String::from_utf8(bytes.clone())
+            .map_err(|_| Error::Custom(format!("{path} is not valid UTF-8")))
+    };
+
+    match format {
+        Format::Puz => Ok(OwnedGrid::from_puz(&bytes)?),
+        Format::Json => {
+            let grid = OwnedGrid::from_json(&text()?)
+                .map_err(|err| Error::Custom(format!("cannot read {path}: {err}")))?;
+            Ok((grid, Metadata::new()))
+        }
+        Format::Ipuz => Ok(OwnedGrid::from_ipuz(&text()?)?),
+        _ => Err(Error::Custom(format!(
+            "{} puzzles cannot be read, use json, puz or ipuz",
+            format.name()
+        ))),
+    }
+}
+
+/// Formats the grid as text: the board, with `.` for empty cells, followed by the
+/// numbered across and down clues.
+fn to_text(grid: &Grid) -> String {
+    let mut text = String::new();
+    for row in grid.board.iter() {
+        text.extend(row.iter().map(|ch| if *ch == ' ' { '.' } else { *ch }));
+        text.push('\n');
+    }
+
+    let clues = grid.clues();
+    for (title, list) in [("Across", &clues.across), ("Down", &clues.down)] {
+        if list.is_empty() {
+            continue;
+        }
+        text.push_str(&format!("\n{title}\n"));
+        for clue in list {
+            text.push_str(&format!(
+                "{:>3}. {} ({})\n",
+                clue.number,
+                if clue.clue.is_empty() {
+                    clue.display_answer()
+                } else {
+                    &clue.clue
+                },
+                clue.length
+            ));
+        }
+    }
+    text
+}
+
+/// Writes the grid in the requested output format.
+fn render(grid: &Grid, args: &Args) -> Result<Vec<u8>, Error> {
+    let format = args.format.unwrap_or(Format::Text);
+    match format {
+        Format::Text => Ok(to_text(grid).into_bytes()),
+        Format::Svg => Ok(grid
+            .to_svg(
+                &SvgOptions::new()
+                    .show_solution(args.solution)
+                    .show_clues(true),
+            )
+            .into_bytes()),
+        Format::Puz => Ok(grid.to_puz(&args.metadata)?),
+        Format::Html => Ok(grid.to_html(&args.metadata).into_bytes()),
+        Format::Pdf => {
+            Ok(grid.to_pdf(&args.metadata, &PdfOptions::new().answer_key(args.solution))?)
+        }
+        Format::Json => grid
+            .to_json_pretty()
+            .map(|json| (json + "\n").into_bytes())
+            .map_err(|err| Error::Custom(err.to_string())),
+        Format::Ipuz => Ok((grid.to_ipuz(&args.metadata)? + "\n").into_bytes()),
+    }
+}
+
+/// Runs the `generate` subcommand, or the bare `WORD...` form when `words` is set.
+fn generate(args: &Args, words: bool) -> Result<(), Error> {
+    let entries = if words {
+        args.positional
+            .iter()
+            .map(|word| (word.trim().to_string(), String::new()))
+            .collect()
+    } else {
+        if args.positional.len() > 1 {
+            return Err(Error::Custom("generate takes at most one FILE".to_string()));
+        }
+        read_entries(args.positional.first().map(String::as_str))?
+    };
+    let entries: Vec<(&str, &str)> = entries
+        .iter()
+        .map(|(answer, clue)| (answer.as_str(), clue.as_str()))
+        .collect();
+
+    let normalized;
+    let (grid, report) = if args.pool {
+        normalized = args.options.normalizer.normalize_entries(&entries);
+        let normalized: Vec<&NormalizedEntry> = normalized.iter().collect();
+        let (grid, report) = generate_from_pool(&args.options, &normalized)?;
+        (grid.map(OwnedGrid::from), report)
+    } else {
+        generate_normalized(&args.options, &entries)?
+    };
+    let Some(grid) = grid.filter(|grid| !grid.words.is_empty()) else {
+        for island in report.islands.iter() {
+            eprintln!("island: {island}");
+        }
+        return Err(Error::Custom(
+            "could not generate a crossword puzzle with the given words".to_string(),
+        ));
+    };
+    // Most of a pool is left out on purpose, so its unused words are not listed.
+    if !args.pool {
+        for word in report.unplaced.iter() {
+            eprintln!("not placed: {} ({})", word.answer, word.reason);
+        }
+    }
+
+    args.write_output(&render(&grid.as_grid(), args)?)
+}
+
+/// Runs the `render` subcommand.
+fn render_saved(args: &Args) -> Result<(), Error> {
+    let [path] = args.positional.as_slice() else {
+        return Err(Error::Custom("render takes exactly one PUZZLE".to_string()));
+    };
+    let (grid, metadata) = read_puzzle(path, args.input_format)?;
+    let args = Args {
+        metadata: Metadata {
+            title: or_saved(&args.metadata.title, metadata.title),
+            author: or_saved(&args.metadata.author, metadata.author),
+            copyright: or_saved(&args.metadata.copyright, metadata.copyright),
+            notes: metadata.notes,
+        },
+        positional: Vec::new(),
+        output: args.output.clone(),
+        options: args.options.clone(),
+        ..*args
+    };
+
+    args.write_output(&render(&grid.as_grid(), &args)?)
+}
+
+/// Returns the value given on the command line, or the saved value if none was given.
+fn or_saved(given: &str, saved: String) -> String {
+    if given.is_empty() {
+        saved
+    } else {
+        given.to_string()
+    }
+}
+
+/// Runs the `validate` subcommand. Reading a saved puzzle already checks its words against
+/// its board; `Grid::validate` then checks that every run of letters is a clued word, and
+/// `Grid::islands` that the words are connected.
+fn validate(args: &Args) -> Result<(), Error> {
+    let [path] = args.positional.as_slice() else {
+        return Err(Error::Custom(
+            "validate takes exactly one PUZZLE".to_string(),
+        ));
+    };
+    let (grid, metadata) = read_puzzle(path, args.input_format)?;
+    if let Err(errors) = grid.validate() {
+        for error in errors.iter() {
+            eprintln!("{error}");
         }
-        Ok(None) => {
-            println!("Could not generate a crossword puzzle with the given words.");
+        return Err(Error::Custom(format!(
+            "{path}: {} problems found",
+            errors.len()
+        )));
+    }
+    let islands = grid.islands();
+    if !islands.is_empty() {
+        for island in islands.iter() {
+            eprintln!("island: {island}");
         }
-        Err(e) => {
-            eprintln!("Error generating puzzle: {e}");
+        return Err(Error::Custom(format!(
+            "{path}: the puzzle falls apart into {} parts",
+            islands.len() + 1
+        )));
+    }
+
+    let width = grid.board.first().map_or(0, |row| row.len());
+    println!(
+        "{path}: valid, {} words on a {width}x{} board{}",
+        grid.words.len(),
+        grid.board.len(),
+        if metadata.title.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", metadata.title)
+        }
+    );
+    Ok(())
+}
+
+/// The main function of the crossword puzzle generator.
+/// It dispatches to the subcommand named by the first argument and reports errors on
+/// stderr with a non-zero exit code.
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let Some(command) = args.first() else {
+        eprint!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    let result = match command.as_str() {
+        "help" | "-h" | "--help" => {
+            print!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        "generate" => Args::parse(&args[1..]).and_then(|args| generate(&args, false)),
+        "render" => Args::parse(&args[1..]).and_then(|args| render_saved(&args)),
+        "validate" => Args::parse(&args[1..]).and_then(|args| validate(&args)),
+        word if word.starts_with('-') || !word.chars().any(char::is_lowercase) => {
+            Args::parse(&args).and_then(|args| generate(&args, true))
+        }
+        _ => {
+            eprintln!("error: unknown command `{command}`");
+            eprint!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
         }
     }
 }
@@ -0,0 +1,77 @@
+//! This module defines `Metadata`, the descriptive information stored alongside a puzzle
+//! by file formats such as Across Lite `.puz`: its title, author, copyright and notes.
+
+/// `Metadata` holds the descriptive information of a puzzle.
+///
+/// Every field defaults to an empty string, which the file formats treat as absent.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Metadata {
+    /// The title of the puzzle.
+    pub title: String,
+    /// The author of the puzzle.
+    pub author: String,
+    /// The copyright notice of the puzzle.
+    pub copyright: String,
+    /// Free-form notes shown to the solver.
+    pub notes: String,
+}
+
+impl Metadata {
+    /// Creates a new `Metadata` instance with every field empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::metadata::Metadata;
+    ///
+    /// let metadata = Metadata::new();
+    /// assert!(metadata.title.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the title of the puzzle.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::metadata::Metadata;
+    ///
+    /// let metadata = Metadata::new().title("Daily Puzzle").author("A. Setter");
+    /// assert_eq!(metadata.title, "Daily Puzzle");
+    /// assert_eq!(metadata.author, "A. Setter");
+    /// ```
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Sets the author of the puzzle.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = author.into();
+        self
+    }
+
+    /// Sets the copyright notice of the puzzle.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn copyright(mut self, copyright: impl Into<String>) -> Self {
+        self.copyright = copyright.into();
+        self
+    }
+
+    /// Sets the notes shown to the solver.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn notes(mut self, notes: impl Into<String>) -> Self {
+        self.notes = notes.into();
+        self
+    }
+}
@@ -0,0 +1,438 @@
+//! This module implements the normalization pipeline that turns the entries of real word
+//! lists, with mixed case, spaces, hyphens, apostrophes and accents, into answers that can
+//! be placed on a grid.
+//!
+//! A `Normalizer` upper-cases an answer with locale-aware rules, optionally strips its
+//! diacritics, and removes spaces and punctuation. The result is a `NormalizedEntry`, which
+//! keeps the original display form of the answer next to the normalized one, so that the
+//! display form and its enumeration (e.g. `ICE CREAM (3,5)`) can be shown with the clue.
+//! Normalized entries are passed to `generate_with` in place of `PuzzleEntry` values, or
+//! `generate_normalized` normalizes the entries itself with the `normalizer` of its options.
+
+use crate::PuzzleEntry;
+
+/// `Locale` selects the casing rules used to upper-case answers.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum Locale {
+    /// The default Unicode casing rules.
+    #[default]
+    Default,
+    /// Turkish and Azerbaijani rules: dotted `i` becomes `İ` and dotless `ı` becomes `I`.
+    Turkish,
+}
+
+/// `Diacritics` determines what happens to accented letters.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum Diacritics {
+    /// Keeps accented letters, so `É` and `E` are different letters on the grid.
+    #[default]
+    Keep,
+    /// Replaces accented Latin letters with their base letter, so `CAFÉ` becomes `CAFE`.
+    Strip,
+}
+
+/// `Normalizer` configures how answers are normalized.
+///
+/// The default normalizer upper-cases with the default Unicode rules, keeps diacritics,
+/// removes punctuation, and leaves clues unchanged. Spaces are always removed from answers,
+/// as a space marks an empty cell on the grid; they are only kept in the display form.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalizer {
+    /// The casing rules used to upper-case answers.
+    pub locale: Locale,
+    /// What happens to accented letters.
+    pub diacritics: Diacritics,
+    /// Whether punctuation, such as hyphens and apostrophes, is removed from answers.
+    pub remove_punctuation: bool,
+    /// Whether the enumeration of multi-word answers, such as `(3,5)`, is appended to their clue.
+    pub append_enumeration: bool,
+}
+
+impl Default for Normalizer {
+    /// Creates the default `Normalizer`.
+    fn default() -> Self {
+        Self {
+            locale: Locale::default(),
+            diacritics: Diacritics::default(),
+            remove_punctuation: true,
+            append_enumeration: false,
+        }
+    }
+}
+
+/// `NormalizedEntry` is an answer after normalization, together with its clue and the
+/// form in which it was originally written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizedEntry {
+    /// The normalized answer, as placed on the grid.
+    pub answer: String,
+    /// The original form of the answer, trimmed.
+    pub display: String,
+    /// The clue of the answer.
+    pub clue: String,
+    /// The enumeration of the answer, such as `(3,5)` for `ICE CREAM`.
+    pub enumeration: String,
+}
+
+impl NormalizedEntry {
+    /// Returns the display form of the answer followed by its enumeration, such as
+    /// `ICE CREAM (3,5)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::normalize::Normalizer;
+    ///
+    /// let entry = Normalizer::new().normalize_entry("ICE CREAM", "Frozen dessert");
+    /// assert_eq!(entry.answer, "ICECREAM");
+    /// assert_eq!(entry.display_with_enumeration(), "ICE CREAM (3,5)");
+    /// ```
+    pub fn display_with_enumeration(&self) -> String {
+        format!("{} {}", self.display, self.enumeration)
+    }
+}
+
+impl<'a> From<&'a NormalizedEntry> for PuzzleEntry<'a> {
+    /// Creates a `PuzzleEntry` from the normalized answer, the clue and, unless it only
+    /// differs from the answer in case, the display form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::normalize::Normalizer;
+    /// use crossword_puzzle::PuzzleEntry;
+    ///
+    /// let normalizer = Normalizer::new();
+    /// let entry = normalizer.normalize_entry("ice cream", "Frozen dessert");
+    /// assert_eq!(PuzzleEntry::from(&entry).display, "ice cream");
+    ///
+    /// let entry = normalizer.normalize_entry("sorbet", "Fruity ice");
+    /// assert_eq!(PuzzleEntry::from(&entry).display, "");
+    /// ```
+    fn from(entry: &'a NormalizedEntry) -> Self {
+        let display = if entry.display.to_uppercase() == entry.answer {
+            ""
+        } else {
+            &entry.display
+        };
+        PuzzleEntry::new(&entry.answer, &entry.clue).display(display)
+    }
+}
+
+/// Returns the base letter of an uppercase Latin letter with a diacritic, or `None` if the
+/// letter has none. Letters that have no single base letter are expanded, like `Æ` to `AE`.
+fn strip_diacritic(ch: char) -> Option<&'static str> {
+    let base = match ch {
+        'À'..='Å' | 'Ā' | 'Ă' | 'Ą' | 'Ǎ' => "A",
+        'Æ' => "AE",
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "C",
+        'Ď' | 'Đ' | 'Ð' => "D",
+        'È'..='Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "G",
+        'Ĥ' | 'Ħ' => "H",
+        'Ì'..='Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' | 'Ǐ' => "I",
+        'Ĳ' => "IJ",
+        'Ĵ' => "J",
+        'Ķ' => "K",
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "L",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => "N",
+        'Ò'..='Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' | 'Ǒ' => "O",
+        'Œ' => "OE",
+        'Ŕ' | 'Ŗ' | 'Ř' => "R",
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' | 'Ș' => "S",
+        'Ţ' | 'Ť' | 'Ŧ' | 'Ț' => "T",
+        'Þ' => "TH",
+        'Ù'..='Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' | 'Ǔ' => "U",
+        'Ŵ' => "W",
+        'Ý' | 'Ŷ' | 'Ÿ' => "Y",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        _ => return None,
+    };
+    Some(base)
+}
+
+/// Returns the uppercase Latin letter made of `base` followed by the combining mark `mark`,
+/// or `None` if there is no such letter among those `strip_diacritic` knows.
+fn compose(base: char, mark: char) -> Option<char> {
+    let (bases, composed) = match mark {
+        '\u{300}' => ("AEIOU", "ÀÈÌÒÙ"),
+        '\u{301}' => ("AEIOUYCLNRSZ", "ÁÉÍÓÚÝĆĹŃŔŚŹ"),
+        '\u{302}' => ("AEIOUCGHJSWY", "ÂÊÎÔÛĈĜĤĴŜŴŶ"),
+        '\u{303}' => ("ANOIU", "ÃÑÕĨŨ"),
+        '\u{304}' => ("AEIOU", "ĀĒĪŌŪ"),
+        '\u{306}' => ("AEGIOU", "ĂĔĞĬŎŬ"),
+        '\u{307}' => ("CEGIZ", "ĊĖĠİŻ"),
+        '\u{308}' => ("AEIOUY", "ÄËÏÖÜŸ"),
+        '\u{30A}' => ("AU", "ÅŮ"),
+        '\u{30B}' => ("OU", "ŐŰ"),
+        '\u{30C}' => ("CDENRSTZAIOU", "ČĎĚŇŘŠŤŽǍǏǑǓ"),
+        '\u{326}' => ("ST", "ȘȚ"),
+        '\u{327}' => ("CGKLNRST", "ÇĢĶĻŅŖŞŢ"),
+        '\u{328}' => ("AEIU", "ĄĘĮŲ"),
+        _ => return None,
+    };
+    let index = bases.chars().position(|ch| ch == base)?;
+    composed.chars().nth(index)
+}
+
+impl Normalizer {
+    /// Creates a new `Normalizer` instance with the default settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::normalize::Normalizer;
+    ///
+    /// assert_eq!(Normalizer::new().normalize("Rock 'n' roll"), "ROCKNROLL");
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the casing rules used to upper-case answers.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::normalize::{Locale, Normalizer};
+    ///
+    /// assert_eq!(Normalizer::new().normalize("kiraz"), "KIRAZ");
+    /// assert_eq!(Normalizer::new().locale(Locale::Turkish).normalize("kiraz ılık"), "KİRAZILIK");
+    /// ```
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets what happens to accented letters.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::normalize::{Diacritics, Normalizer};
+    ///
+    /// assert_eq!(Normalizer::new().normalize("Crème brûlée"), "CRÈMEBRÛLÉE");
+    /// assert_eq!(
+    ///     Normalizer::new().diacritics(Diacritics::Strip).normalize("Crème brûlée"),
+    ///     "CREMEBRULEE"
+    /// );
+    /// ```
+    pub fn diacritics(mut self, diacritics: Diacritics) -> Self {
+        self.diacritics = diacritics;
+        self
+    }
+
+    /// Sets whether punctuation, such as hyphens and apostrophes, is removed from answers.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn remove_punctuation(mut self, remove_punctuation: bool) -> Self {
+        self.remove_punctuation = remove_punctuation;
+        self
+    }
+
+    /// Sets whether the enumeration of multi-word answers is appended to their clue.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::normalize::Normalizer;
+    ///
+    /// let normalizer = Normalizer::new().append_enumeration(true);
+    /// assert_eq!(normalizer.normalize_entry("ice cream", "Frozen dessert").clue, "Frozen dessert (3,5)");
+    /// assert_eq!(normalizer.normalize_entry("sorbet", "Fruity ice").clue, "Fruity ice");
+    /// ```
+    pub fn append_enumeration(mut self, append_enumeration: bool) -> Self {
+        self.append_enumeration = append_enumeration;
+        self
+    }
+
+    /// Upper-cases a single character with the rules of the locale.
+    fn upper_case(&self, ch: char, out: &mut String) {
+        match (self.locale, ch) {
+            (Locale::Turkish, 'i') => out.push('İ'),
+            (Locale::Turkish, 'ı') => out.push('I'),
+            _ => out.extend(ch.to_uppercase()),
+        }
+    }
+
+    /// Normalizes an answer.
+    ///
+    /// The answer is upper-cased with the rules of the `locale`, and combining marks are
+    /// composed with the letter before them, so a decomposed `é` gives the same answer as a
+    /// precomposed one. Whitespace is always removed, and every other character that is not
+    /// a letter or digit, including combining marks that could not be composed, is removed
+    /// if `remove_punctuation` is set. Finally, accented Latin letters are replaced with
+    /// their base letter if `diacritics` is `Diacritics::Strip`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The answer as written in the word list.
+    ///
+    /// # Returns
+    ///
+    /// The normalized answer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::normalize::Normalizer;
+    ///
+    /// let normalizer = Normalizer::new();
+    /// assert_eq!(normalizer.normalize("mother-in-law"), "MOTHERINLAW");
+    /// assert_eq!(normalizer.normalize("  Straße "), "STRASSE");
+    /// assert_eq!(normalizer.normalize("cafe\u{301}"), "CAFÉ");
+    /// assert_eq!(normalizer.normalize("café"), "CAFÉ");
+    ///
+    /// let keep_punctuation = Normalizer::new().remove_punctuation(false);
+    /// assert_eq!(keep_punctuation.normalize("rock 'n' roll"), "ROCK'N'ROLL");
+    /// ```
+    pub fn normalize(&self, text: &str) -> String {
+        let mut upper = String::with_capacity(text.len());
+        for ch in text.trim().chars() {
+            if let Some(composed) = upper.chars().last().and_then(|base| compose(base, ch)) {
+                upper.pop();
+                upper.push(composed);
+            } else if !ch.is_whitespace() && (ch.is_alphanumeric() || !self.remove_punctuation) {
+                self.upper_case(ch, &mut upper);
+            }
+        }
+
+        match self.diacritics {
+            Diacritics::Keep => upper,
+            Diacritics::Strip => upper
+                .chars()
+                .filter(|ch| !('\u{300}'..='\u{36F}').contains(ch))
+                .fold(String::with_capacity(upper.len()), |mut out, ch| {
+                    match strip_diacritic(ch) {
+                        Some(base) => out.push_str(base),
+                        None => out.push(ch),
+                    }
+                    out
+                }),
+        }
+    }
+
+    /// Returns the enumeration of an answer: the number of letters of each of its words,
+    /// separated by `,` between words and by `-` between the parts of a hyphenated word.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::normalize::Normalizer;
+    ///
+    /// let normalizer = Normalizer::new();
+    /// assert_eq!(normalizer.enumeration("ICE CREAM"), "(3,5)");
+    /// assert_eq!(normalizer.enumeration("mother-in-law"), "(6-2-3)");
+    /// assert_eq!(normalizer.enumeration("don't"), "(4)");
+    /// ```
+    pub fn enumeration(&self, text: &str) -> String {
+        let letters = Self {
+            remove_punctuation: true,
+            ..self.clone()
+        };
+        let words: Vec<String> = text
+            .split_whitespace()
+            .filter_map(|word| {
+                let parts: Vec<String> = word
+                    .split('-')
+                    .map(|part| letters.normalize(part).chars().count())
+                    .filter(|count| *count > 0)
+                    .map(|count| count.to_string())
+                    .collect();
+                (!parts.is_empty()).then(|| parts.join("-"))
+            })
+            .collect();
+        format!("({})", words.join(","))
+    }
+
+    /// Normalizes an answer and keeps its display form, enumeration and clue.
+    ///
+    /// If `append_enumeration` is set and the answer has more than one word or part, the
+    /// enumeration is appended to the clue.
+    ///
+    /// # Arguments
+    ///
+    /// * `answer` - The answer as written in the word list.
+    /// * `clue` - The clue of the answer.
+    ///
+    /// # Returns
+    ///
+    /// A `NormalizedEntry` holding the normalized answer, its display form and its clue.
+    pub fn normalize_entry(&self, answer: &str, clue: &str) -> NormalizedEntry {
+        let enumeration = self.enumeration(answer);
+        let clue = clue.trim();
+        let clue = if self.append_enumeration && enumeration.contains([',', '-']) {
+            if clue.is_empty() {
+                enumeration.clone()
+            } else {
+                format!("{clue} {enumeration}")
+            }
+        } else {
+            clue.to_string()
+        };
+
+        NormalizedEntry {
+            answer: self.normalize(answer),
+            display: answer.trim().to_string(),
+            clue,
+            enumeration,
+        }
+    }
+
+    /// Normalizes a list of entries, dropping those whose answer is empty after
+    /// normalization.
+    ///
+    /// This is the step that runs before generation: the returned entries are passed to
+    /// `generate_with` or `generate_with_report` as `&NormalizedEntry` values.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The answers with their clues, as written in the word list.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<NormalizedEntry>` in the order of `entries`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::generate_with;
+    /// use crossword_puzzle::normalize::{Diacritics, Normalizer};
+    /// use crossword_puzzle::options::GeneratorOptions;
+    ///
+    /// let normalizer = Normalizer::new().diacritics(Diacritics::Strip);
+    /// let entries = normalizer.normalize_entries(&[
+    ///     ("ice cream", "Frozen dessert"),
+    ///     ("Crème brûlée", "Custard with a burnt top"),
+    ///     ("sorbet", "Fruity ice"),
+    ///     ("---", "Dropped, nothing is left"),
+    /// ]);
+    /// assert_eq!(entries.len(), 3);
+    /// assert_eq!(entries[1].answer, "CREMEBRULEE");
+    ///
+    /// let refs: Vec<_> = entries.iter().collect();
+    /// let grid = generate_with(&GeneratorOptions::new(), &refs).unwrap().unwrap();
+    /// assert_eq!(grid.words.len(), 3);
+    ///
+    /// // The clue lists show the answers as they were written.
+    /// let clues = grid.clues();
+    /// let mut shown: Vec<_> = clues.across.iter().chain(clues.down.iter())
+    ///     .map(|clue| clue.display_answer())
+    ///     .collect();
+    /// shown.sort();
+    /// assert_eq!(shown, ["Crème brûlée", "SORBET", "ice cream"]);
+    /// ```
+    pub fn normalize_entries(&self, entries: &[(&str, &str)]) -> Vec<NormalizedEntry> {
+        entries
+            .iter()
+            .map(|(answer, clue)| self.normalize_entry(answer, clue))
+            .filter(|entry| !entry.answer.trim().is_empty())
+            .collect()
+    }
+}
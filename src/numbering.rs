@@ -0,0 +1,288 @@
+//! This module implements standard crossword clue numbering for a `Grid`.
+//! Cells that start a word are numbered in reading order (left to right, top to bottom),
+//! and the placed words are grouped into across and down clue lists sorted by number.
+
+use crate::error::FormatError;
+use crate::word::{Direction, Position, Word};
+use crate::Grid;
+
+/// `Clue` is a single numbered entry in an across or down clue list.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Clue {
+    /// The number printed in the cell where the word starts.
+    pub number: usize,
+    /// The full answer of the word.
+    pub answer: String,
+    /// The clue associated with the word.
+    pub clue: String,
+    /// The answer as it is shown in answer lists, or an empty string to show the answer
+    /// itself (see `Clue::display_answer`).
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "String::is_empty"))]
+    pub display: String,
+    /// The `Position` of the first character of the word.
+    pub position: Position,
+    /// The number of cells the word occupies.
+    pub length: usize,
+}
+
+impl Clue {
+    /// Returns the answer as it is shown in answer lists: its display form, such as
+    /// `ICE CREAM`, if the word has one, or else the answer itself.
+    pub fn display_answer(&self) -> &str {
+        if self.display.is_empty() {
+            &self.answer
+        } else {
+            &self.display
+        }
+    }
+}
+
+/// `Clues` holds the across and down clue lists of a `Grid`, each sorted by clue number.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Clues {
+    /// The clues of all horizontally placed words.
+    pub across: Vec<Clue>,
+    /// The clues of all vertically placed words.
+    pub down: Vec<Clue>,
+}
+
+impl Grid<'_> {
+    /// Returns the numbered cells of the grid in reading order.
+    ///
+    /// The board is scanned row by row, left to right, and every cell where at least one
+    /// placed `Word` starts receives the next number, beginning at 1. A cell that starts both
+    /// an across and a down word receives a single number.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` of `(number, Position)` pairs sorted by number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction, Position}};
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.add_word(Word::value("", 'T', "EST").unwrap().direction(Direction::Horizontal)).unwrap();
+    /// grid.add_word(Word::value("", 'T', "EAM").unwrap().position(0, 0).direction(Direction::Vertical)).unwrap();
+    /// grid.add_word(Word::value("", 'T', "OP").unwrap().position(3, 0).direction(Direction::Vertical)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     grid.numbers(),
+    ///     vec![(1, Position { x: 0, y: 0 }), (2, Position { x: 3, y: 0 })]
+    /// );
+    /// ```
+    pub fn numbers(&self) -> Vec<(usize, Position)> {
+        let mut numbers = Vec::new();
+
+        for (y, row) in self.board.iter().enumerate() {
+            for x in 0..row.len() {
+                let position = Position { x, y };
+                if self
+                    .words
+                    .iter()
+                    .any(|word| word.direction != Direction::NotSet && word.origin == position)
+                {
+                    numbers.push((numbers.len() + 1, position));
+                }
+            }
+        }
+
+        numbers
+    }
+
+    /// Returns the clue number of the cell at the given `Position`, if that cell starts a word.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The `Position` (x, y) of the cell.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(usize)` if a word starts at `position`.
+    /// - `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction, Position}};
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.add_word(Word::value("", 'T', "EST").unwrap().direction(Direction::Horizontal)).unwrap();
+    ///
+    /// assert_eq!(grid.number_at(Position { x: 0, y: 0 }), Some(1));
+    /// assert_eq!(grid.number_at(Position { x: 1, y: 0 }), None);
+    /// ```
+    pub fn number_at(&self, position: Position) -> Option<usize> {
+        self.numbers()
+            .into_iter()
+            .find(|(_, pos)| *pos == position)
+            .map(|(number, _)| number)
+    }
+
+    /// Builds the across and down clue lists of the grid.
+    ///
+    /// Every placed `Word` is assigned the number of the cell it starts in (see `numbers`).
+    /// Horizontal words go into the across list and vertical words into the down list,
+    /// both sorted by number.
+    ///
+    /// # Returns
+    ///
+    /// A `Clues` struct containing the across and down clue lists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction}};
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.add_word(Word::value("", 'T', "EST").unwrap().direction(Direction::Horizontal).clue("Exam")).unwrap();
+    /// grid.add_word(Word::value("", 'T', "EAM").unwrap().position(0, 0).direction(Direction::Vertical).clue("Squad")).unwrap();
+    ///
+    /// let clues = grid.clues();
+    /// assert_eq!(clues.across.len(), 1);
+    /// assert_eq!(clues.across[0].number, 1);
+    /// assert_eq!(clues.across[0].answer, "TEST");
+    /// assert_eq!(clues.down[0].number, 1);
+    /// assert_eq!(clues.down[0].clue, "Squad");
+    /// ```
+    pub fn clues(&self) -> Clues {
+        let numbers = self.numbers();
+        let mut clues = Clues::default();
+
+        for word in self.words.iter() {
+            let Some(number) = numbers
+                .iter()
+                .find(|(_, pos)| *pos == word.origin)
+                .map(|(number, _)| *number)
+            else {
+                continue;
+            };
+
+            let answer = word.segment.full_word_str();
+            let clue = Clue {
+                number,
+                length: answer.chars().count(),
+                answer,
+                clue: word.clue.to_string(),
+                display: word.display.to_string(),
+                position: word.origin,
+            };
+
+            match word.direction {
+                Direction::Horizontal => clues.across.push(clue),
+                Direction::Vertical => clues.down.push(clue),
+                Direction::NotSet => {}
+            }
+        }
+
+        clues.across.sort_by_key(|clue| clue.number);
+        clues.down.sort_by_key(|clue| clue.number);
+        clues
+    }
+}
+
+/// `Run` is a sequence of two or more consecutive letters on a board, which solvers read
+/// as a word of the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Run {
+    /// The clue number of the first cell.
+    pub(crate) number: usize,
+    /// The first cell of the run.
+    pub(crate) position: Position,
+    /// The direction of the run.
+    pub(crate) direction: Direction,
+    /// The number of cells of the run.
+    pub(crate) length: usize,
+}
+
+impl Run {
+    /// Returns the letters of the run on `board`.
+    pub(crate) fn letters(&self, board: &[Vec<char>]) -> String {
+        (0..self.length)
+            .map(|i| match self.direction {
+                Direction::Vertical => board[self.position.y + i][self.position.x],
+                _ => board[self.position.y][self.position.x + i],
+            })
+            .collect()
+    }
+}
+
+/// Returns the runs of `board` in numbering order: reading order of their first cell, with
+/// the across run before the down run when both start in the same cell. Cells that start a
+/// run are numbered from 1 in the same order.
+pub(crate) fn letter_runs(board: &[Vec<char>]) -> Vec<Run> {
+    let is_letter = |x: usize, y: usize| {
+        board
+            .get(y)
+            .and_then(|row| row.get(x))
+            .is_some_and(|ch| *ch != ' ')
+    };
+    let mut runs = Vec::new();
+    let mut number = 0;
+
+    for (y, row) in board.iter().enumerate() {
+        for x in 0..row.len() {
+            if !is_letter(x, y) {
+                continue;
+            }
+            let position = Position { x, y };
+            let across = (x == 0 || !is_letter(x - 1, y)) && is_letter(x + 1, y);
+            let down = (y == 0 || !is_letter(x, y - 1)) && is_letter(x, y + 1);
+            if across || down {
+                number += 1;
+            }
+
+            if across {
+                runs.push(Run {
+                    number,
+                    position,
+                    direction: Direction::Horizontal,
+                    length: (x..).take_while(|x| is_letter(*x, y)).count(),
+                });
+            }
+            if down {
+                runs.push(Run {
+                    number,
+                    position,
+                    direction: Direction::Vertical,
+                    length: (y..).take_while(|y| is_letter(x, *y)).count(),
+                });
+            }
+        }
+    }
+
+    runs
+}
+
+impl<'a> Grid<'a> {
+    /// Pairs every run of the board with the placed word that fills it, in numbering order.
+    ///
+    /// File formats describe a puzzle by its board and clue list only, and solvers treat
+    /// every run as a word, so each run needs a placed word with a clue.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::UncluedRun` for the first run that is not a placed word.
+    pub(crate) fn clued_runs(&self) -> Result<Vec<(Run, &Word<'a>)>, FormatError> {
+        letter_runs(&self.board)
+            .into_iter()
+            .map(|run| {
+                self.words
+                    .iter()
+                    .find(|word| {
+                        word.origin == run.position
+                            && word.direction == run.direction
+                            && word.segment.len() == run.length
+                    })
+                    .map(|word| (run, word))
+                    .ok_or(FormatError::UncluedRun {
+                        position: run.position,
+                        direction: run.direction,
+                    })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,347 @@
+//! This module defines `GeneratorOptions`, the set of knobs that control how
+//! `generate_with` searches for a crossword layout, together with the ordering
+//! strategies it supports for words and candidate placements.
+
+use std::time::Duration;
+
+use crate::normalize::Normalizer;
+
+/// `WordOrder` determines the order in which words are taken from the queue during generation.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum WordOrder {
+    /// Places the longest words first. Long words give later words more letters to cross.
+    #[default]
+    LongestFirst,
+    /// Places the shortest words first.
+    ShortestFirst,
+    /// Places the words in the order they were given.
+    AsGiven,
+}
+
+/// `PlacementOrder` determines the order in which the valid placements of a word are tried.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum PlacementOrder {
+    /// Tries placements closest to the centre of the board first, which keeps the layout compact.
+    #[default]
+    CenterFirst,
+    /// Tries placements that cross the most existing letters first, breaking ties by
+    /// distance to the centre of the board.
+    MostCrossings,
+    /// Tries placements in the order they were found on the board.
+    AsFound,
+}
+
+/// `GeneratorOptions` configures the crossword generation performed by `generate_with`.
+///
+/// The default options reproduce the behavior of `generate`: every word gets three
+/// attempts, words are placed longest first, placements closest to the board centre are
+/// tried first, neither the number of words nor the board size is limited, no seed
+/// is set, the search has no time or node budget, and disconnected grids are accepted.
+/// Generators that compare several layouts build three of them, and `generate_normalized`
+/// uses the default `Normalizer`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratorOptions {
+    /// The number of attempts made to place each word before it is left out.
+    pub retries: usize,
+    /// The number of complete layouts built by `generate_with_required` and
+    /// `generate_from_pool` before the best one is returned.
+    pub layouts: usize,
+    /// The order in which words are placed.
+    pub word_order: WordOrder,
+    /// The order in which the valid placements of a word are tried.
+    pub placement_order: PlacementOrder,
+    /// The maximum number of words to place, or `None` for no limit.
+    pub max_words: Option<usize>,
+    /// The maximum number of columns of the board, or `None` for no limit.
+    pub max_width: Option<usize>,
+    /// The maximum number of rows of the board, or `None` for no limit.
+    pub max_height: Option<usize>,
+    /// The seed used to randomise the layout, or `None` for the deterministic default order.
+    pub seed: Option<u64>,
+    /// The wall-clock time the search may take, or `None` for no limit.
+    pub timeout: Option<Duration>,
+    /// The maximum number of placements the search may try, or `None` for no limit.
+    pub max_nodes: Option<usize>,
+    /// Whether a grid whose words do not form a single connected component is rejected.
+    pub require_connected: bool,
+    /// The normalizer applied to the answers given to `generate_normalized`.
+    pub normalizer: Normalizer,
+}
+
+impl Default for GeneratorOptions {
+    /// Creates the default `GeneratorOptions`.
+    fn default() -> Self {
+        Self {
+            retries: 3,
+            layouts: 3,
+            word_order: WordOrder::default(),
+            placement_order: PlacementOrder::default(),
+            max_words: None,
+            max_width: None,
+            max_height: None,
+            seed: None,
+            timeout: None,
+            max_nodes: None,
+            require_connected: false,
+            normalizer: Normalizer::default(),
+        }
+    }
+}
+
+impl GeneratorOptions {
+    /// Creates a new `GeneratorOptions` instance with the default settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::options::{GeneratorOptions, WordOrder};
+    ///
+    /// let options = GeneratorOptions::new();
+    /// assert_eq!(options.retries, 3);
+    /// assert_eq!(options.word_order, WordOrder::LongestFirst);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of attempts made to place each word before it is left out.
+    ///
+    /// A word that cannot be placed yet is re-queued until its attempts are used up,
+    /// so later words have a chance to open up a crossing for it. Values below `1`
+    /// are treated as `1`.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::options::GeneratorOptions;
+    ///
+    /// let options = GeneratorOptions::new().retries(5);
+    /// assert_eq!(options.retries, 5);
+    /// ```
+    pub fn retries(mut self, retries: usize) -> Self {
+        self.retries = retries.max(1);
+        self
+    }
+
+    /// Sets the number of complete layouts built by `generate_with_required` and
+    /// `generate_from_pool` before the best one is returned. More layouts give a better
+    /// puzzle but take longer. The default is `3`, and values below `1` are treated as `1`.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::options::GeneratorOptions;
+    ///
+    /// assert_eq!(GeneratorOptions::new().layouts, 3);
+    /// assert_eq!(GeneratorOptions::new().layouts(10).layouts, 10);
+    /// ```
+    pub fn layouts(mut self, layouts: usize) -> Self {
+        self.layouts = layouts.max(1);
+        self
+    }
+
+    /// Sets the order in which words are placed.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::options::{GeneratorOptions, WordOrder};
+    ///
+    /// let options = GeneratorOptions::new().word_order(WordOrder::AsGiven);
+    /// assert_eq!(options.word_order, WordOrder::AsGiven);
+    /// ```
+    pub fn word_order(mut self, word_order: WordOrder) -> Self {
+        self.word_order = word_order;
+        self
+    }
+
+    /// Sets the order in which the valid placements of a word are tried.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::options::{GeneratorOptions, PlacementOrder};
+    ///
+    /// let options = GeneratorOptions::new().placement_order(PlacementOrder::MostCrossings);
+    /// assert_eq!(options.placement_order, PlacementOrder::MostCrossings);
+    /// ```
+    pub fn placement_order(mut self, placement_order: PlacementOrder) -> Self {
+        self.placement_order = placement_order;
+        self
+    }
+
+    /// Limits the number of words placed on the grid.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::options::GeneratorOptions;
+    ///
+    /// let options = GeneratorOptions::new().max_words(20);
+    /// assert_eq!(options.max_words, Some(20));
+    /// ```
+    pub fn max_words(mut self, max_words: usize) -> Self {
+        self.max_words = Some(max_words);
+        self
+    }
+
+    /// Limits the size of the board to `max_width` columns and `max_height` rows.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::options::GeneratorOptions;
+    ///
+    /// let options = GeneratorOptions::new().max_size(15, 15);
+    /// assert_eq!(options.max_width, Some(15));
+    /// assert_eq!(options.max_height, Some(15));
+    /// ```
+    pub fn max_size(mut self, max_width: usize, max_height: usize) -> Self {
+        self.max_width = Some(max_width);
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Sets the seed used to randomise the layout.
+    ///
+    /// With a seed, words of equal rank under the `word_order` and placements of equal rank
+    /// under the `placement_order` are tried in a pseudo-random order derived from the seed.
+    /// Different seeds give different layouts from the same word list, and the same seed
+    /// always reproduces the same layout.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::generate_with;
+    /// use crossword_puzzle::options::GeneratorOptions;
+    ///
+    /// let words = &["LOREM", "IPSUM", "DOLOR", "SIT", "AMET"];
+    /// let options = GeneratorOptions::new().seed(42);
+    ///
+    /// let first = generate_with(&options, words).unwrap().unwrap();
+    /// let second = generate_with(&options, words).unwrap().unwrap();
+    /// assert_eq!(first.board, second.board);
+    /// ```
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Limits the wall-clock time the search may take.
+    ///
+    /// When the time runs out, the grid built so far is returned and the words that were
+    /// not tried yet are reported as `DropReason::BudgetExhausted`.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use crossword_puzzle::options::GeneratorOptions;
+    ///
+    /// let options = GeneratorOptions::new().timeout(Duration::from_secs(2));
+    /// assert_eq!(options.timeout, Some(Duration::from_secs(2)));
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Limits the number of placements the search may try.
+    ///
+    /// Every placement added to the grid during the search counts as one node. When the
+    /// budget runs out, the grid built so far is returned and the words that were not
+    /// tried yet are reported as `DropReason::BudgetExhausted`.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::generate_with_report;
+    /// use crossword_puzzle::options::GeneratorOptions;
+    /// use crossword_puzzle::report::DropReason;
+    ///
+    /// let words = &["LOREM", "IPSUM", "DOLOR", "SIT", "AMET"];
+    /// let options = GeneratorOptions::new().max_nodes(2);
+    /// let (grid, report) = generate_with_report(&options, words).unwrap();
+    ///
+    /// assert_eq!(grid.unwrap().words.len(), 2);
+    /// assert!(report.budget_exhausted);
+    /// assert_eq!(report.unplaced.len(), 3);
+    /// assert!(report.unplaced.iter().all(|w| w.reason == DropReason::BudgetExhausted));
+    /// ```
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Sets whether a grid whose words do not form a single connected component is
+    /// rejected.
+    ///
+    /// When set, a search that ends with a disconnected grid (see `Grid::is_connected`)
+    /// returns `None` instead of the grid, and the islands of the rejected grid are
+    /// listed in `GenerationReport::islands`.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate_with, generate_with_anchors};
+    /// use crossword_puzzle::options::GeneratorOptions;
+    /// use crossword_puzzle::word::{Direction, Word};
+    ///
+    /// let words = &["LOREM", "IPSUM", "DOLOR", "SIT", "AMET"];
+    /// let options = GeneratorOptions::new().require_connected(true);
+    ///
+    /// let grid = generate_with(&options, words).unwrap().unwrap();
+    /// assert!(grid.is_connected());
+    ///
+    /// // Two anchors that nothing connects are rejected, and the report says why.
+    /// let cat = Word::value("", 'C', "AT").unwrap().direction(Direction::Horizontal);
+    /// let dog = Word::value("", 'D', "OG").unwrap().position(0, 4).direction(Direction::Horizontal);
+    /// let options = options.max_size(5, 5);
+    /// let (grid, report) = generate_with_anchors(&options, &[cat, dog], &["QUIZ"]).unwrap();
+    /// assert!(grid.is_none());
+    /// assert_eq!(report.islands.len(), 1);
+    /// assert_eq!(report.islands[0].answers, vec!["DOG"]);
+    /// ```
+    pub fn require_connected(mut self, require_connected: bool) -> Self {
+        self.require_connected = require_connected;
+        self
+    }
+
+    /// Sets the normalizer applied to the answers given to `generate_normalized`.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::normalize::{Locale, Normalizer};
+    /// use crossword_puzzle::options::GeneratorOptions;
+    ///
+    /// let options = GeneratorOptions::new().normalizer(Normalizer::new().locale(Locale::Turkish));
+    /// assert_eq!(options.normalizer.locale, Locale::Turkish);
+    /// ```
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+}
@@ -0,0 +1,428 @@
+//! This module defines `OwnedGrid`, an owned copy of a `Grid` that stores its answers and
+//! clues as `String`s instead of borrowing them from the word list it was generated from.
+//! An `OwnedGrid` can outlive that word list, be stored in long-lived structs or caches,
+//! and be sent across threads. It converts from a `Grid` and borrows back as one.
+
+use crate::error::GridError;
+use crate::numbering::{letter_runs, Clues, Run};
+use crate::score::LayoutScore;
+use crate::word::{Direction, Position, Segment, Word, WordRepr};
+use crate::Grid;
+
+/// `OwnedSegment` is the owned counterpart of `Segment`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OwnedSegment {
+    /// The part of the word that comes before the `crossed` character.
+    pub prefix: String,
+    /// The single character at the crossing point, which connects to another word.
+    pub crossed: char,
+    /// The part of the word that comes after the `crossed` character.
+    pub suffix: String,
+}
+
+impl OwnedSegment {
+    /// Borrows the `OwnedSegment` as a `Segment`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{owned::OwnedSegment, word::Segment};
+    ///
+    /// let owned = OwnedSegment::from(Segment::new("TE", 'S', "T").unwrap());
+    /// assert_eq!(owned.as_segment().full_word_str(), "TEST");
+    /// ```
+    pub fn as_segment(&self) -> Segment<'_> {
+        Segment {
+            prefix: &self.prefix,
+            crossed: self.crossed,
+            suffix: &self.suffix,
+        }
+    }
+}
+
+impl From<Segment<'_>> for OwnedSegment {
+    /// Copies a `Segment` into an `OwnedSegment`.
+    fn from(segment: Segment<'_>) -> Self {
+        Self {
+            prefix: segment.prefix.to_string(),
+            crossed: segment.crossed,
+            suffix: segment.suffix.to_string(),
+        }
+    }
+}
+
+/// `OwnedWord` is the owned counterpart of `Word`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OwnedWord {
+    /// The segment of the word.
+    pub segment: OwnedSegment,
+    /// The position of the crossed character on the grid.
+    pub position: Position,
+    /// The clue associated with this word.
+    pub clue: String,
+    /// The answer as it is shown in answer lists, or an empty string to show the answer itself.
+    pub display: String,
+    /// The calculated origin of the word based on its position and direction.
+    pub origin: Position,
+    /// The direction of the word (horizontal or vertical).
+    pub direction: Direction,
+}
+
+impl OwnedWord {
+    /// Borrows the `OwnedWord` as a `Word`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{owned::OwnedWord, word::{Word, Direction}};
+    ///
+    /// let word = Word::value("", 'T', "EST").unwrap().clue("An exam").direction(Direction::Horizontal);
+    /// let owned = OwnedWord::from(word);
+    ///
+    /// assert_eq!(owned.clue, "An exam");
+    /// assert_eq!(owned.as_word().segment.full_word_str(), "TEST");
+    /// ```
+    pub fn as_word(&self) -> Word<'_> {
+        Word {
+            segment: self.segment.as_segment(),
+            position: self.position,
+            clue: &self.clue,
+            display: &self.display,
+            origin: self.origin,
+            direction: self.direction,
+        }
+    }
+
+    /// Returns the full answer of the word.
+    pub fn answer(&self) -> String {
+        self.segment.as_segment().full_word_str()
+    }
+}
+
+impl From<Word<'_>> for OwnedWord {
+    /// Copies a `Word` into an `OwnedWord`.
+    fn from(word: Word<'_>) -> Self {
+        Self {
+            segment: word.segment.into(),
+            position: word.position,
+            clue: word.clue.to_string(),
+            display: word.display.to_string(),
+            origin: word.origin,
+            direction: word.direction,
+        }
+    }
+}
+
+/// `OwnedGrid` is the owned counterpart of `Grid`.
+///
+/// It holds the same words and board as the `Grid` it was created from, with every answer
+/// and clue copied into a `String`, so it has no lifetime and is `Send + Sync + 'static`.
+/// Use `as_grid` to get a `Grid` view of it for the methods that only exist on `Grid`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OwnedGrid {
+    /// The words placed on the grid.
+    pub words: Vec<OwnedWord>,
+    /// The 2D vector of characters representing the crossword board itself.
+    /// Empty cells are represented by a space character (' ').
+    pub board: Vec<Vec<char>>,
+    /// The maximum number of columns the board may grow to, or `None` for no limit.
+    pub max_width: Option<usize>,
+    /// The maximum number of rows the board may grow to, or `None` for no limit.
+    pub max_height: Option<usize>,
+}
+
+impl From<Grid<'_>> for OwnedGrid {
+    /// Copies a `Grid` into an `OwnedGrid`.
+    fn from(grid: Grid<'_>) -> Self {
+        Self {
+            words: grid.words.into_iter().map(OwnedWord::from).collect(),
+            board: grid.board,
+            max_width: grid.max_width,
+            max_height: grid.max_height,
+        }
+    }
+}
+
+impl<'a> From<&'a OwnedGrid> for Grid<'a> {
+    /// Borrows an `OwnedGrid` as a `Grid` (see `OwnedGrid::as_grid`).
+    fn from(grid: &'a OwnedGrid) -> Self {
+        grid.as_grid()
+    }
+}
+
+impl OwnedGrid {
+    /// Borrows the `OwnedGrid` as a `Grid`.
+    ///
+    /// The words of the returned `Grid` borrow their answers and clues from `self`; the
+    /// board is copied. The `Grid` can be used with every `Grid` method, including
+    /// placing more words, and turned back into an `OwnedGrid` with `OwnedGrid::from`.
+    ///
+    /// # Returns
+    ///
+    /// A `Grid` with the same words, board and size limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate, owned::OwnedGrid};
+    ///
+    /// let owned = {
+    ///     let words = vec![String::from("LOREM"), String::from("IPSUM"), String::from("DOLOR")];
+    ///     let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    ///     OwnedGrid::from(generate(&words).unwrap().unwrap())
+    /// };
+    ///
+    /// // The owned grid outlives the word list and can be sent to another thread.
+    /// let handle = std::thread::spawn(move || owned);
+    /// let owned = handle.join().unwrap();
+    ///
+    /// let grid = owned.as_grid();
+    /// assert_eq!(grid.board, owned.board);
+    /// assert_eq!(grid.words.len(), 3);
+    /// ```
+    pub fn as_grid(&self) -> Grid<'_> {
+        Grid {
+            words: self.words.iter().map(OwnedWord::as_word).collect(),
+            board: self.board.clone(),
+            max_width: self.max_width,
+            max_height: self.max_height,
+        }
+    }
+
+    /// Builds an `OwnedGrid` from a board alone, placing a word on every run of two or more
+    /// letters. This is how puzzles are read from file formats, which store the board and
+    /// the clue list but not the words.
+    ///
+    /// `clue` is called once per run, in numbering order, and returns its clue.
+    pub(crate) fn from_board(
+        board: Vec<Vec<char>>,
+        mut clue: impl FnMut(&Run) -> String,
+    ) -> Result<Self, GridError> {
+        let runs = letter_runs(&board);
+        let answers: Vec<String> = runs.iter().map(|run| run.letters(&board)).collect();
+        let clues: Vec<String> = runs.iter().map(&mut clue).collect();
+        let reprs: Vec<WordRepr> = runs
+            .iter()
+            .zip(answers.iter().zip(clues.iter()))
+            .map(|(run, (answer, clue))| WordRepr {
+                answer,
+                clue,
+                display: "",
+                position: run.position,
+                orientation: run.direction,
+            })
+            .collect();
+
+        Ok(Grid::from_word_reprs(&reprs, board)?.into())
+    }
+
+    /// Returns the character at a given position on the board (see `Grid::get_char`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, owned::OwnedGrid, word::{Word, Direction, Position}};
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.add_word(Word::value("", 'T', "EST").unwrap().direction(Direction::Horizontal)).unwrap();
+    /// let owned = OwnedGrid::from(grid);
+    ///
+    /// assert_eq!(owned.get_char(Position { x: 1, y: 0 }), Some('E'));
+    /// assert_eq!(owned.get_char(Position { x: 4, y: 0 }), None);
+    /// ```
+    pub fn get_char(&self, position: Position) -> Option<char> {
+        self.board
+            .get(position.y)
+            .and_then(|row| row.get(position.x))
+            .copied()
+    }
+
+    /// Returns the numbered cells of the grid in reading order (see `Grid::numbers`).
+    pub fn numbers(&self) -> Vec<(usize, Position)> {
+        self.as_grid().numbers()
+    }
+
+    /// Returns the clue number printed in the cell at `position` (see `Grid::number_at`).
+    pub fn number_at(&self, position: Position) -> Option<usize> {
+        self.as_grid().number_at(position)
+    }
+
+    /// Returns the numbered across and down clue lists (see `Grid::clues`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate_with_clues, owned::OwnedGrid, PuzzleEntry};
+    ///
+    /// let entries = [PuzzleEntry::new("TEST", "An exam"), PuzzleEntry::new("TOP", "Summit")];
+    /// let owned = OwnedGrid::from(generate_with_clues(&entries).unwrap().unwrap());
+    ///
+    /// let clues = owned.clues();
+    /// assert_eq!(clues.across.len() + clues.down.len(), 2);
+    /// assert_eq!(owned.numbers().len(), 2);
+    /// ```
+    pub fn clues(&self) -> Clues {
+        self.as_grid().clues()
+    }
+
+    /// Computes the `LayoutScore` of the grid (see `Grid::score`).
+    pub fn score(&self) -> LayoutScore {
+        self.as_grid().score()
+    }
+
+    /// Serializes the `OwnedGrid` into a JSON string, in the same format as `Grid::to_json`.
+    ///
+    /// This function requires the `serde` feature to be enabled.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(String)` containing the JSON representation of the grid.
+    /// - `Err(serde_json::Error)` if serialization fails.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self)
+    }
+
+    /// Serializes the `OwnedGrid` into a pretty-printed JSON string, in the same format as
+    /// `Grid::to_json_pretty`.
+    ///
+    /// This function requires the `serde` feature to be enabled.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(String)` containing the pretty-printed JSON representation of the grid.
+    /// - `Err(serde_json::Error)` if serialization fails.
+    #[cfg(feature = "serde")]
+    pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&self)
+    }
+
+    /// Deserializes an `OwnedGrid` from a JSON string produced by `to_json` or
+    /// `Grid::to_json`.
+    ///
+    /// The words are rebuilt from their `answer`, `position` and `orientation`, and the board
+    /// is checked against them: a board letter that does not match a word, or that belongs
+    /// to no word, is rejected. The answers and clues are copied, so they may contain JSON
+    /// escape sequences and the result does not borrow from `json`. Use `as_grid` to get a
+    /// `Grid` from it.
+    ///
+    /// This function requires the `serde` feature to be enabled.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(OwnedGrid)` if `json` describes a valid grid.
+    /// - `Err(serde_json::Error)` if deserialization or validation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate_with_clues, owned::OwnedGrid, PuzzleEntry};
+    ///
+    /// let entries = [
+    ///     PuzzleEntry::new("TEST", "An \"exam\""),
+    ///     PuzzleEntry::new("TOP", "Summit"),
+    /// ];
+    /// let grid = generate_with_clues(&entries).unwrap().unwrap();
+    /// let json = grid.to_json().unwrap();
+    ///
+    /// let owned = OwnedGrid::from_json(&json).unwrap();
+    /// assert_eq!(owned.board, grid.board);
+    /// assert!(owned.words.iter().any(|word| word.clue == "An \"exam\""));
+    /// assert_eq!(owned.to_json().unwrap(), json);
+    ///
+    /// // A board that does not match the words is rejected.
+    /// let tampered = json.replacen("\"T\"", "\"X\"", 1);
+    /// assert!(OwnedGrid::from_json(&tampered).is_err());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Serializes the `OwnedWord` exactly like the equivalent `Word`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for OwnedWord {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_word().serialize(serializer)
+    }
+}
+
+/// Serializes the `OwnedGrid` exactly like the equivalent `Grid`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for OwnedGrid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_grid().serialize(serializer)
+    }
+}
+
+/// The serialized form of an `OwnedWord`, mirroring the field names used by `Word`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct OwnedWordRepr {
+    answer: String,
+    #[serde(default)]
+    clue: String,
+    #[serde(default)]
+    display: String,
+    position: Position,
+    orientation: Direction,
+}
+
+#[cfg(feature = "serde")]
+impl OwnedWordRepr {
+    /// Borrows the owned strings as a `WordRepr`.
+    fn as_repr(&self) -> crate::word::WordRepr<'_> {
+        crate::word::WordRepr {
+            answer: &self.answer,
+            clue: &self.clue,
+            display: &self.display,
+            position: self.position,
+            orientation: self.orientation,
+        }
+    }
+}
+
+/// The serialized form of an `OwnedGrid`. The `clues` are derived data and are ignored.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct OwnedGridRepr {
+    words: Vec<OwnedWordRepr>,
+    board: Vec<Vec<char>>,
+}
+
+/// Deserializes an `OwnedWord` like `Word`, crossing at its first character.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OwnedWord {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let repr = OwnedWordRepr::deserialize(deserializer)?;
+        let word = repr
+            .as_repr()
+            .into_word(0)
+            .map_err(serde::de::Error::custom)?;
+        Ok(word.into())
+    }
+}
+
+/// Deserializes an `OwnedGrid` like `Grid`, validating the words against the board.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OwnedGrid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let repr = OwnedGridRepr::deserialize(deserializer)?;
+        let reprs: Vec<_> = repr.words.iter().map(OwnedWordRepr::as_repr).collect();
+        let grid = Grid::from_word_reprs(&reprs, repr.board).map_err(serde::de::Error::custom)?;
+        Ok(grid.into())
+    }
+}
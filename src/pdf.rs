@@ -0,0 +1,619 @@
+//! This module writes a `Grid` as a printable PDF worksheet, without external binaries or
+//! dependencies.
+//!
+//! The first page holds the title, the blank numbered grid and the across and down clue
+//! columns, which flow onto further pages if they do not fit. An optional last page holds
+//! the answer key. Text is set in the standard Helvetica fonts that every PDF reader
+//! provides, so no font is embedded. The font encoding is Windows-1252, extended with the
+//! Latin letters outside it that the document uses and the standard fonts provide, such as
+//! the Turkish `İ`, `Ş` and `Ğ` or the Polish `Ł`. Other characters are rejected with
+//! `FormatError::UnsupportedCharacter`.
+
+use crate::error::FormatError;
+use crate::metadata::Metadata;
+use crate::owned::OwnedGrid;
+use crate::svg::{clue_line, wrap};
+use crate::Grid;
+
+/// The empty space left around the content of a page, in points.
+const MARGIN: f64 = 48.0;
+/// The largest size of a grid cell, in points.
+const MAX_CELL_SIZE: f64 = 28.0;
+/// The font size of the clue columns, in points.
+const CLUE_FONT_SIZE: f64 = 10.0;
+/// The distance between two lines of the clue columns, in points.
+const CLUE_LINE_HEIGHT: f64 = 13.0;
+/// The space between the two clue columns, in points.
+const COLUMN_GAP: f64 = 24.0;
+
+/// `PageSize` is the paper size of the pages of a PDF worksheet.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum PageSize {
+    /// ISO A4, 210 × 297 mm.
+    #[default]
+    A4,
+    /// US Letter, 8.5 × 11 in.
+    Letter,
+}
+
+impl PageSize {
+    /// Returns the width and height of the page, in points.
+    fn dimensions(self) -> (f64, f64) {
+        match self {
+            PageSize::A4 => (595.0, 842.0),
+            PageSize::Letter => (612.0, 792.0),
+        }
+    }
+}
+
+/// `PdfOptions` configures the PDF worksheet written by `Grid::to_pdf`.
+///
+/// The default options print on A4 paper without an answer key.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct PdfOptions {
+    /// The paper size of the pages.
+    pub page_size: PageSize,
+    /// Whether a last page with the filled grid is added.
+    pub answer_key: bool,
+}
+
+impl PdfOptions {
+    /// Creates a new `PdfOptions` instance with the default settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::pdf::{PageSize, PdfOptions};
+    ///
+    /// let options = PdfOptions::new();
+    /// assert_eq!(options.page_size, PageSize::A4);
+    /// assert!(!options.answer_key);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the paper size of the pages.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn page_size(mut self, page_size: PageSize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Sets whether a last page with the filled grid is added.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::pdf::PdfOptions;
+    ///
+    /// let options = PdfOptions::new().answer_key(true);
+    /// assert!(options.answer_key);
+    /// ```
+    pub fn answer_key(mut self, answer_key: bool) -> Self {
+        self.answer_key = answer_key;
+        self
+    }
+}
+
+/// The codes left undefined by the Windows-1252 encoding. They are assigned to the letters
+/// an `Encoding` adds.
+const FREE_CODES: [u8; 36] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10,
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1A, 0x1B, 0x1C, 0x1D, 0x1E, 0x1F, 0x81,
+    0x8D, 0x8F, 0x90, 0x9D,
+];
+
+/// Returns the Windows-1252 code of `ch`, or `None` if the character set lacks it.
+fn win_ansi_code(ch: char) -> Option<u8> {
+    let code = match ch {
+        ' '..='~' | '\u{A0}'..='\u{FF}' => ch as u8,
+        '€' => 0x80,
+        '‚' => 0x82,
+        'ƒ' => 0x83,
+        '„' => 0x84,
+        '…' => 0x85,
+        '†' => 0x86,
+        '‡' => 0x87,
+        'ˆ' => 0x88,
+        '‰' => 0x89,
+        'Š' => 0x8A,
+        '‹' => 0x8B,
+        'Œ' => 0x8C,
+        'Ž' => 0x8E,
+        '‘' => 0x91,
+        '’' => 0x92,
+        '“' => 0x93,
+        '”' => 0x94,
+        '•' => 0x95,
+        '–' => 0x96,
+        '—' => 0x97,
+        '˜' => 0x98,
+        '™' => 0x99,
+        'š' => 0x9A,
+        '›' => 0x9B,
+        'œ' => 0x9C,
+        'ž' => 0x9E,
+        'Ÿ' => 0x9F,
+        _ => return None,
+    };
+    Some(code)
+}
+
+/// Returns the glyph name of a letter outside Windows-1252 that the standard Helvetica
+/// fonts provide, or `None` if they lack it.
+fn glyph_name(ch: char) -> Option<&'static str> {
+    let name = match ch {
+        'Ā' => "Amacron",
+        'ā' => "amacron",
+        'Ă' => "Abreve",
+        'ă' => "abreve",
+        'Ą' => "Aogonek",
+        'ą' => "aogonek",
+        'Ć' => "Cacute",
+        'ć' => "cacute",
+        'Č' => "Ccaron",
+        'č' => "ccaron",
+        'Ď' => "Dcaron",
+        'ď' => "dcaron",
+        'Đ' => "Dcroat",
+        'đ' => "dcroat",
+        'Ē' => "Emacron",
+        'ē' => "emacron",
+        'Ė' => "Edotaccent",
+        'ė' => "edotaccent",
+        'Ę' => "Eogonek",
+        'ę' => "eogonek",
+        'Ě' => "Ecaron",
+        'ě' => "ecaron",
+        'Ğ' => "Gbreve",
+        'ğ' => "gbreve",
+        'Ģ' => "Gcommaaccent",
+        'ģ' => "gcommaaccent",
+        'Ī' => "Imacron",
+        'ī' => "imacron",
+        'Į' => "Iogonek",
+        'į' => "iogonek",
+        'İ' => "Idotaccent",
+        'ı' => "dotlessi",
+        'Ķ' => "Kcommaaccent",
+        'ķ' => "kcommaaccent",
+        'Ĺ' => "Lacute",
+        'ĺ' => "lacute",
+        'Ļ' => "Lcommaaccent",
+        'ļ' => "lcommaaccent",
+        'Ľ' => "Lcaron",
+        'ľ' => "lcaron",
+        'Ł' => "Lslash",
+        'ł' => "lslash",
+        'Ń' => "Nacute",
+        'ń' => "nacute",
+        'Ņ' => "Ncommaaccent",
+        'ņ' => "ncommaaccent",
+        'Ň' => "Ncaron",
+        'ň' => "ncaron",
+        'Ō' => "Omacron",
+        'ō' => "omacron",
+        'Ő' => "Ohungarumlaut",
+        'ő' => "ohungarumlaut",
+        'Ŕ' => "Racute",
+        'ŕ' => "racute",
+        'Ŗ' => "Rcommaaccent",
+        'ŗ' => "rcommaaccent",
+        'Ř' => "Rcaron",
+        'ř' => "rcaron",
+        'Ś' => "Sacute",
+        'ś' => "sacute",
+        'Ş' => "Scedilla",
+        'ş' => "scedilla",
+        'Ţ' => "Tcommaaccent",
+        'ţ' => "tcommaaccent",
+        'Ť' => "Tcaron",
+        'ť' => "tcaron",
+        'Ū' => "Umacron",
+        'ū' => "umacron",
+        'Ů' => "Uring",
+        'ů' => "uring",
+        'Ű' => "Uhungarumlaut",
+        'ű' => "uhungarumlaut",
+        'Ų' => "Uogonek",
+        'ų' => "uogonek",
+        'Ź' => "Zacute",
+        'ź' => "zacute",
+        'Ż' => "Zdotaccent",
+        'ż' => "zdotaccent",
+        'Ș' => "Scommaaccent",
+        'ș' => "scommaaccent",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// The font encoding of a document: Windows-1252, extended with the letters outside it
+/// that the document uses, each assigned one of the `FREE_CODES`.
+#[derive(Default)]
+struct Encoding {
+    /// The added letters with their code and glyph name, in the order they were found.
+    extra: Vec<(char, u8, &'static str)>,
+}
+
+impl Encoding {
+    /// Builds the encoding for every character of `texts`.
+    ///
+    /// Returns `FormatError::UnsupportedCharacter` for the first character that the standard
+    /// fonts lack, or that finds no free code left.
+    fn new<'t>(texts: impl IntoIterator<Item = &'t str>) -> Result<Self, FormatError> {
+        let mut encoding = Encoding::default();
+        for ch in texts.into_iter().flat_map(str::chars) {
+            if win_ansi_code(ch).is_some() || encoding.extra.iter().any(|(c, _, _)| *c == ch) {
+                continue;
+            }
+            let name = glyph_name(ch).ok_or(FormatError::UnsupportedCharacter(ch))?;
+            let code = *FREE_CODES
+                .get(encoding.extra.len())
+                .ok_or(FormatError::UnsupportedCharacter(ch))?;
+            encoding.extra.push((ch, code, name));
+        }
+        Ok(encoding)
+    }
+
+    /// Encodes `text` as a PDF string literal body, escaping the characters that are
+    /// special in PDF strings. Characters missing from the encoding are left out.
+    fn encode(&self, text: &str) -> String {
+        let mut encoded = String::with_capacity(text.len());
+        for ch in text.chars() {
+            let code = win_ansi_code(ch).or_else(|| {
+                self.extra
+                    .iter()
+                    .find(|(c, _, _)| *c == ch)
+                    .map(|(_, code, _)| *code)
+            });
+            match code {
+                Some(b'\\' | b'(' | b')') => {
+                    encoded.push('\\');
+                    encoded.push(ch);
+                }
+                Some(code @ b' '..=b'~') => encoded.push(code as char),
+                Some(code) => encoded.push_str(&format!("\\{code:03o}")),
+                None => {}
+            }
+        }
+        encoded
+    }
+
+    /// Returns the PDF encoding dictionary.
+    fn object(&self) -> String {
+        let differences: Vec<String> = self
+            .extra
+            .iter()
+            .map(|(_, code, name)| format!("{code} /{name}"))
+            .collect();
+        format!(
+            "<< /Type /Encoding /BaseEncoding /WinAnsiEncoding /Differences [{}] >>",
+            differences.join(" ")
+        )
+    }
+}
+
+/// The content stream of a single page. Coordinates are measured in points from the
+/// top-left corner of the page and converted to PDF coordinates when drawing.
+struct Page<'e> {
+    height: f64,
+    content: String,
+    encoding: &'e Encoding,
+}
+
+impl<'e> Page<'e> {
+    /// Creates an empty page of the given height, whose text is written with `encoding`.
+    fn new(height: f64, encoding: &'e Encoding) -> Self {
+        Self {
+            height,
+            content: String::new(),
+            encoding,
+        }
+    }
+
+    /// Draws a square cell with a black border, filled with the given gray level
+    /// (0 is black, 1 is white).
+    fn cell(&mut self, left: f64, top: f64, size: f64, gray: f64) {
+        self.content.push_str(&format!(
+            "{gray:.2} g 0 G 0.75 w {left:.2} {:.2} {size:.2} {size:.2} re B\n",
+            self.height - top - size
+        ));
+    }
+
+    /// Draws a line of text whose baseline starts at the given point.
+    fn text(&mut self, left: f64, baseline: f64, size: f64, bold: bool, text: &str) {
+        let font = if bold { "F2" } else { "F1" };
+        self.content.push_str(&format!(
+            "0 g BT /{font} {size:.2} Tf {left:.2} {:.2} Td ({}) Tj ET\n",
+            self.height - baseline,
+            self.encoding.encode(text)
+        ));
+    }
+}
+
+/// Lays out the board in cells of at most `MAX_CELL_SIZE` points, fitting `width` and
+/// `height`, and returns the cell size.
+fn cell_size(grid: &Grid, width: f64, height: f64) -> f64 {
+    let rows = grid.board.len().max(1) as f64;
+    let columns = grid.board.first().map_or(0, |row| row.len()).max(1) as f64;
+    (width / columns).min(height / rows).min(MAX_CELL_SIZE)
+}
+
+/// Draws the board with its top-left corner at `(left, top)`. Letters are drawn only if
+/// `solution` is set; clue numbers are always drawn.
+fn draw_board(page: &mut Page, grid: &Grid, left: f64, top: f64, cell: f64, solution: bool) {
+    for (y, row) in grid.board.iter().enumerate() {
+        for (x, ch) in row.iter().enumerate() {
+            let cell_left = left + x as f64 * cell;
+            let cell_top = top + y as f64 * cell;
+            if *ch == ' ' {
+                page.cell(cell_left, cell_top, cell, 0.2);
+                continue;
+            }
+            page.cell(cell_left, cell_top, cell, 1.0);
+            if solution {
+                let size = cell * 0.55;
+                page.text(
+                    cell_left + (cell - size * 0.67) / 2.0,
+                    cell_top + cell * 0.8,
+                    size,
+                    false,
+                    &ch.to_string(),
+                );
+            }
+        }
+    }
+
+    let size = cell * 0.3;
+    for (number, position) in grid.numbers() {
+        page.text(
+            left + position.x as f64 * cell + 1.5,
+            top + position.y as f64 * cell + size + 1.0,
+            size,
+            false,
+            &number.to_string(),
+        );
+    }
+}
+
+/// Draws the title and author of the puzzle at the top of a page and returns the
+/// vertical position below them.
+fn draw_heading(page: &mut Page, metadata: &Metadata, title: &str) -> f64 {
+    let mut top = MARGIN;
+    if !title.is_empty() {
+        top += 18.0;
+        page.text(MARGIN, top, 18.0, true, title);
+        top += 8.0;
+    }
+    if !metadata.author.is_empty() {
+        top += 11.0;
+        page.text(MARGIN, top, 11.0, false, &metadata.author);
+        top += 6.0;
+    }
+    top
+}
+
+/// Assembles the pages into a PDF document whose fonts use `encoding`.
+fn write_document(pages: Vec<Page>, encoding: &Encoding, width: f64, height: f64) -> Vec<u8> {
+    let mut objects = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        String::new(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding 5 0 R >>".to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding 5 0 R >>".to_string(),
+        encoding.object(),
+    ];
+    let mut kids = Vec::new();
+    for page in pages {
+        let page_id = objects.len() + 1;
+        kids.push(format!("{page_id} 0 R"));
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {width} {height}] /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+            page_id + 1
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            page.content.len(),
+            page.content
+        ));
+    }
+    objects[1] = format!(
+        "<< /Type /Pages /Kids [{}] /Count {} >>",
+        kids.join(" "),
+        kids.len()
+    );
+
+    let mut pdf = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{object}\nendobj\n", i + 1).as_bytes());
+    }
+    let xref = pdf.len();
+    pdf.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    pdf.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .as_bytes(),
+    );
+    pdf
+}
+
+impl Grid<'_> {
+    /// Writes the grid as a printable PDF worksheet.
+    ///
+    /// The first page shows the title and author from `metadata`, the blank grid with its
+    /// clue numbers, and the across and down clues (see `Grid::clues`) in two columns.
+    /// Clues that do not fit flow onto further pages. If `options.answer_key` is set, a last
+    /// page shows the filled grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The title and author printed at the top of the pages.
+    /// * `options` - The `PdfOptions` controlling the paper size and the answer key.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<u8>)` containing the PDF document.
+    /// - `Err(FormatError)` if a character cannot be printed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::UnsupportedCharacter` for a character that the standard fonts
+    /// lack, or if the document uses more than 36 distinct letters outside Windows-1252.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate_with_clues, metadata::Metadata, PuzzleEntry};
+    /// use crossword_puzzle::pdf::PdfOptions;
+    ///
+    /// let entries = [
+    ///     PuzzleEntry::new("LOREM", "Placeholder text opener"),
+    ///     PuzzleEntry::new("IPSUM", "Placeholder text, part two"),
+    ///     PuzzleEntry::new("DOLOR", "Pain (in Latin)"),
+    /// ];
+    /// let grid = generate_with_clues(&entries).unwrap().unwrap();
+    /// let metadata = Metadata::new().title("Latin worksheet");
+    ///
+    /// let pdf = grid.to_pdf(&metadata, &PdfOptions::new().answer_key(true)).unwrap();
+    /// assert!(pdf.starts_with(b"%PDF-1.4"));
+    /// assert!(pdf.ends_with(b"%%EOF\n"));
+    ///
+    /// let text = String::from_utf8_lossy(&pdf);
+    /// assert!(text.contains("/Count 2"));
+    /// assert!(text.contains("Pain \\(in Latin\\)"));
+    /// ```
+    ///
+    /// Turkish and Central European letters are added to the font encoding:
+    ///
+    /// ```
+    /// use crossword_puzzle::error::FormatError;
+    /// use crossword_puzzle::{generate_with_clues, metadata::Metadata, PuzzleEntry};
+    /// use crossword_puzzle::pdf::PdfOptions;
+    ///
+    /// let entries = [PuzzleEntry::new("İŞ", "Work"), PuzzleEntry::new("ŞİĞ", "Shallow")];
+    /// let grid = generate_with_clues(&entries).unwrap().unwrap();
+    /// let pdf = grid.to_pdf(&Metadata::new(), &PdfOptions::new().answer_key(true)).unwrap();
+    ///
+    /// let text = String::from_utf8_lossy(&pdf);
+    /// for glyph in ["/Idotaccent", "/Scedilla", "/Gbreve"] {
+    ///     assert!(text.contains(glyph));
+    /// }
+    /// assert!(!text.contains('?'));
+    ///
+    /// // Characters the standard fonts lack are rejected instead of printed as `?`.
+    /// let entries = [PuzzleEntry::new("ΑΒΓ", "Greek"), PuzzleEntry::new("ΓΑΒ", "Greek")];
+    /// let grid = generate_with_clues(&entries).unwrap().unwrap();
+    /// assert!(matches!(
+    ///     grid.to_pdf(&Metadata::new(), &PdfOptions::new()),
+    ///     Err(FormatError::UnsupportedCharacter(_))
+    /// ));
+    /// ```
+    pub fn to_pdf(
+        &self,
+        metadata: &Metadata,
+        options: &PdfOptions,
+    ) -> Result<Vec<u8>, FormatError> {
+        let (width, height) = options.page_size.dimensions();
+        let content_width = width - 2.0 * MARGIN;
+        let bottom = height - MARGIN;
+        let column_width = (content_width - COLUMN_GAP) / 2.0;
+        let chars_per_line = (column_width / (CLUE_FONT_SIZE * 0.5)) as usize;
+        let clues = self.clues();
+        let mut lines = Vec::new();
+        for (title, list) in [("Across", &clues.across), ("Down", &clues.down)] {
+            if list.is_empty() {
+                continue;
+            }
+            if !lines.is_empty() {
+                lines.push((false, String::new()));
+            }
+            lines.push((true, title.to_string()));
+            for clue in list {
+                lines.extend(
+                    wrap(&clue_line(clue), chars_per_line)
+                        .into_iter()
+                        .map(|line| (false, line)),
+                );
+            }
+        }
+
+        let board: Vec<String> = self.board.iter().map(|row| row.iter().collect()).collect();
+        let texts = [metadata.title.as_str(), metadata.author.as_str()]
+            .into_iter()
+            .chain(lines.iter().map(|(_, line)| line.as_str()))
+            .chain(board.iter().map(String::as_str));
+        let encoding = Encoding::new(texts)?;
+
+        let mut pages = Vec::new();
+        let mut page = Page::new(height, &encoding);
+        let top = draw_heading(&mut page, metadata, &metadata.title) + 12.0;
+        let cell = cell_size(self, content_width, (bottom - top) / 2.0);
+        let columns = self.board.first().map_or(0, |row| row.len()) as f64;
+        let rows = self.board.len() as f64;
+        let grid_left = MARGIN + (content_width - columns * cell) / 2.0;
+        draw_board(&mut page, self, grid_left, top, cell, false);
+
+        let mut column_top = top + rows * cell + 24.0;
+        let mut column = 0;
+        let mut baseline = column_top;
+        for (bold, line) in lines {
+            while baseline + CLUE_LINE_HEIGHT > bottom {
+                column += 1;
+                if column == 2 {
+                    pages.push(std::mem::replace(&mut page, Page::new(height, &encoding)));
+                    column = 0;
+                    column_top = MARGIN;
+                }
+                baseline = column_top;
+            }
+            baseline += CLUE_LINE_HEIGHT;
+            let left = MARGIN + column as f64 * (column_width + COLUMN_GAP);
+            page.text(left, baseline, CLUE_FONT_SIZE, bold, &line);
+        }
+        pages.push(page);
+
+        if options.answer_key {
+            let mut page = Page::new(height, &encoding);
+            let title = if metadata.title.is_empty() {
+                "Answer key".to_string()
+            } else {
+                format!("{} - Answer key", metadata.title)
+            };
+            let top = draw_heading(&mut page, metadata, &title) + 12.0;
+            let cell = cell_size(self, content_width, bottom - top);
+            let grid_left = MARGIN + (content_width - columns * cell) / 2.0;
+            draw_board(&mut page, self, grid_left, top, cell, true);
+            pages.push(page);
+        }
+
+        Ok(write_document(pages, &encoding, width, height))
+    }
+}
+
+impl OwnedGrid {
+    /// Writes the grid as a printable PDF worksheet (see `Grid::to_pdf`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `FormatError::UnsupportedCharacter` for a character that cannot be printed.
+    pub fn to_pdf(
+        &self,
+        metadata: &Metadata,
+        options: &PdfOptions,
+    ) -> Result<Vec<u8>, FormatError> {
+        self.as_grid().to_pdf(metadata, options)
+    }
+}
@@ -0,0 +1,380 @@
+//! This module reads and writes puzzles in the Across Lite `.puz` binary format, which is
+//! understood by most crossword solving apps.
+//!
+//! A `.puz` file starts with a fixed 52 byte header holding the board size, the number of
+//! clues and a set of checksums, followed by the solution grid, the blank player grid, and
+//! NUL-terminated strings: the title, author, copyright, every clue in numbering order, and
+//! the notes. Text is encoded as ISO-8859-1 (Latin-1), so only characters up to `U+00FF`
+//! can be stored.
+
+use crate::error::FormatError;
+use crate::metadata::Metadata;
+use crate::numbering::letter_runs;
+use crate::owned::OwnedGrid;
+use crate::Grid;
+
+/// The file magic stored at offset `0x02` of the header.
+const MAGIC: &[u8; 12] = b"ACROSS&DOWN\0";
+/// The format version written to the header.
+const VERSION: &[u8; 4] = b"1.3\0";
+/// The length of the header in bytes.
+const HEADER_LEN: usize = 0x34;
+/// The byte used for a block (empty cell) in the solution and player grids.
+const BLOCK: u8 = b'.';
+/// The byte used for an unfilled cell in the player grid.
+const BLANK: u8 = b'-';
+
+/// Computes the `.puz` checksum of `data`, continuing from `sum`.
+fn checksum(data: &[u8], mut sum: u16) -> u16 {
+    for byte in data {
+        if sum & 1 != 0 {
+            sum = (sum >> 1) | 0x8000;
+        } else {
+            sum >>= 1;
+        }
+        sum = sum.wrapping_add(*byte as u16);
+    }
+    sum
+}
+
+/// Encodes `text` as Latin-1.
+fn encode(text: &str) -> Result<Vec<u8>, FormatError> {
+    text.chars()
+        .map(|ch| u8::try_from(ch).map_err(|_| FormatError::UnsupportedCharacter(ch)))
+        .collect()
+}
+
+/// Decodes Latin-1 `bytes` into a `String`.
+fn decode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| *byte as char).collect()
+}
+
+/// The encoded strings that follow the grids in a `.puz` file.
+struct Strings {
+    title: Vec<u8>,
+    author: Vec<u8>,
+    copyright: Vec<u8>,
+    clues: Vec<Vec<u8>>,
+    notes: Vec<u8>,
+}
+
+impl Strings {
+    /// Computes the checksum of the strings, continuing from `sum`.
+    ///
+    /// The title, author, copyright and notes are included with their NUL terminator, and
+    /// only if they are not empty. The clues are included without their terminator.
+    fn checksum(&self, mut sum: u16) -> u16 {
+        for text in [&self.title, &self.author, &self.copyright] {
+            if !text.is_empty() {
+                sum = checksum(text, sum);
+                sum = checksum(&[0], sum);
+            }
+        }
+        for clue in self.clues.iter() {
+            sum = checksum(clue, sum);
+        }
+        if !self.notes.is_empty() {
+            sum = checksum(&self.notes, sum);
+            sum = checksum(&[0], sum);
+        }
+        sum
+    }
+}
+
+/// The checksums stored in a `.puz` header.
+struct Checksums {
+    global: u16,
+    cib: u16,
+    masked_low: [u8; 4],
+    masked_high: [u8; 4],
+}
+
+impl Checksums {
+    /// Computes the checksums of a puzzle from its header fields (`cib`, the 8 bytes at
+    /// offset `0x2C`), grids and strings.
+    fn compute(cib: &[u8], solution: &[u8], player: &[u8], strings: &Strings) -> Self {
+        let cib = checksum(cib, 0);
+        let solution_sum = checksum(solution, 0);
+        let player_sum = checksum(player, 0);
+        let strings_sum = strings.checksum(0);
+        let global = strings.checksum(checksum(player, checksum(solution, cib)));
+
+        let sums = [cib, solution_sum, player_sum, strings_sum];
+        let mut masked_low = *b"ICHE";
+        let mut masked_high = *b"ATED";
+        for (i, sum) in sums.iter().enumerate() {
+            masked_low[i] ^= (sum & 0xFF) as u8;
+            masked_high[i] ^= (sum >> 8) as u8;
+        }
+
+        Self {
+            global,
+            cib,
+            masked_low,
+            masked_high,
+        }
+    }
+}
+
+impl Grid<'_> {
+    /// Writes the grid as an Across Lite `.puz` file.
+    ///
+    /// Empty cells become blocks, and every run of two or more letters on the board must be
+    /// a placed word, whose clue is written in standard numbering order (see `Grid::clues`).
+    /// The player grid is written blank, so the file opens as an unsolved puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The title, author, copyright and notes to store in the file.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Vec<u8>)` containing the bytes of the `.puz` file.
+    /// - `Err(FormatError)` if the grid cannot be represented in the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FormatError` if:
+    /// - The board is empty (`InvalidData`), or wider or taller than 255 cells (`GridTooLarge`).
+    /// - A letter, clue or metadata field contains a character outside Latin-1 (`UnsupportedCharacter`).
+    /// - A run of letters on the board is not a placed word (`UncluedRun`).
+    /// - The puzzle has more than 65535 clues (`TooManyClues`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate_with_clues, metadata::Metadata, owned::OwnedGrid, PuzzleEntry};
+    ///
+    /// let entries = [
+    ///     PuzzleEntry::new("LOREM", "Placeholder text opener"),
+    ///     PuzzleEntry::new("IPSUM", "Placeholder text, part two"),
+    ///     PuzzleEntry::new("DOLOR", "Pain, in Latin"),
+    ///     PuzzleEntry::new("SIT", "Take a seat"),
+    ///     PuzzleEntry::new("AMET", "Placeholder text closer"),
+    /// ];
+    /// let grid = generate_with_clues(&entries).unwrap().unwrap();
+    /// let metadata = Metadata::new().title("Lorem").author("A. Setter").copyright("© 2024");
+    ///
+    /// let bytes = grid.to_puz(&metadata).unwrap();
+    /// assert_eq!(&bytes[2..14], b"ACROSS&DOWN\0");
+    ///
+    /// let (loaded, loaded_metadata) = OwnedGrid::from_puz(&bytes).unwrap();
+    /// assert_eq!(loaded.board, grid.board);
+    /// assert_eq!(loaded.clues(), grid.clues());
+    /// assert_eq!(loaded_metadata, metadata);
+    /// ```
+    pub fn to_puz(&self, metadata: &Metadata) -> Result<Vec<u8>, FormatError> {
+        let height = self.board.len();
+        let width = self.board.first().map_or(0, |row| row.len());
+        if width == 0 || height == 0 {
+            return Err(FormatError::InvalidData("the board is empty".to_string()));
+        }
+        if width > u8::MAX as usize || height > u8::MAX as usize {
+            return Err(FormatError::GridTooLarge { width, height });
+        }
+
+        let mut solution = Vec::with_capacity(width * height);
+        let mut player = Vec::with_capacity(width * height);
+        for row in self.board.iter() {
+            for ch in row.iter() {
+                if *ch == ' ' {
+                    solution.push(BLOCK);
+                    player.push(BLOCK);
+                } else {
+                    solution.push(
+                        u8::try_from(*ch).map_err(|_| FormatError::UnsupportedCharacter(*ch))?,
+                    );
+                    player.push(BLANK);
+                }
+            }
+        }
+
+        let clues = self
+            .clued_runs()?
+            .into_iter()
+            .map(|(_, word)| encode(word.clue))
+            .collect::<Result<Vec<_>, _>>()?;
+        let clue_count =
+            u16::try_from(clues.len()).map_err(|_| FormatError::TooManyClues(clues.len()))?;
+
+        let strings = Strings {
+            title: encode(&metadata.title)?,
+            author: encode(&metadata.author)?,
+            copyright: encode(&metadata.copyright)?,
+            clues,
+            notes: encode(&metadata.notes)?,
+        };
+
+        let mut cib = Vec::with_capacity(8);
+        cib.push(width as u8);
+        cib.push(height as u8);
+        cib.extend_from_slice(&clue_count.to_le_bytes());
+        cib.extend_from_slice(&1u16.to_le_bytes());
+        cib.extend_from_slice(&0u16.to_le_bytes());
+        let checksums = Checksums::compute(&cib, &solution, &player, &strings);
+
+        let mut bytes = Vec::with_capacity(HEADER_LEN + 2 * solution.len());
+        bytes.extend_from_slice(&checksums.global.to_le_bytes());
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&checksums.cib.to_le_bytes());
+        bytes.extend_from_slice(&checksums.masked_low);
+        bytes.extend_from_slice(&checksums.masked_high);
+        bytes.extend_from_slice(VERSION);
+        bytes.resize(0x2C, 0);
+        bytes.extend_from_slice(&cib);
+        bytes.extend_from_slice(&solution);
+        bytes.extend_from_slice(&player);
+        for text in [&strings.title, &strings.author, &strings.copyright]
+            .into_iter()
+            .chain(strings.clues.iter())
+            .chain([&strings.notes])
+        {
+            bytes.extend_from_slice(text);
+            bytes.push(0);
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl OwnedGrid {
+    /// Writes the grid as an Across Lite `.puz` file (see `Grid::to_puz`).
+    pub fn to_puz(&self, metadata: &Metadata) -> Result<Vec<u8>, FormatError> {
+        self.as_grid().to_puz(metadata)
+    }
+
+    /// Reads an Across Lite `.puz` file.
+    ///
+    /// Blocks become empty cells, every run of two or more letters becomes a placed `Word`,
+    /// and the clues are assigned to the words in numbering order. All checksums of the
+    /// file are verified. Sections following the notes, such as rebus or circled-cell
+    /// data, are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The contents of the `.puz` file.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((OwnedGrid, Metadata))` with the puzzle and its title, author, copyright and notes.
+    /// - `Err(FormatError)` if the file cannot be read.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `FormatError` if:
+    /// - The file is truncated, scrambled, or its clue count does not match its board (`InvalidData`).
+    /// - A checksum does not match (`ChecksumMismatch`).
+    /// - The board contains a letter that belongs to no run (`GridError`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate, error::FormatError, metadata::Metadata, owned::OwnedGrid};
+    ///
+    /// let grid = generate(&["LOREM", "IPSUM", "DOLOR", "SIT", "AMET"]).unwrap().unwrap();
+    /// let mut bytes = grid.to_puz(&Metadata::new()).unwrap();
+    ///
+    /// let (loaded, _) = OwnedGrid::from_puz(&bytes).unwrap();
+    /// assert_eq!(loaded.board, grid.board);
+    /// assert_eq!(loaded.words.len(), 5);
+    ///
+    /// // Changing a letter of the solution breaks the checksums.
+    /// bytes[0x34] ^= 1;
+    /// assert!(matches!(OwnedGrid::from_puz(&bytes), Err(FormatError::ChecksumMismatch(_))));
+    /// ```
+    pub fn from_puz(bytes: &[u8]) -> Result<(Self, Metadata), FormatError> {
+        let truncated = || FormatError::InvalidData("the file is truncated".to_string());
+
+        let start = bytes
+            .windows(MAGIC.len())
+            .position(|window| window == MAGIC)
+            .and_then(|index| index.checked_sub(2))
+            .ok_or_else(|| FormatError::InvalidData("the file magic is missing".to_string()))?;
+        let bytes = &bytes[start..];
+        let header = bytes.get(..HEADER_LEN).ok_or_else(truncated)?;
+        let read_u16 = |offset: usize| u16::from_le_bytes([header[offset], header[offset + 1]]);
+
+        let width = header[0x2C] as usize;
+        let height = header[0x2D] as usize;
+        let clue_count = read_u16(0x2E) as usize;
+        if read_u16(0x32) != 0 {
+            return Err(FormatError::InvalidData(
+                "scrambled puzzles are not supported".to_string(),
+            ));
+        }
+
+        let area = width * height;
+        let solution = bytes
+            .get(HEADER_LEN..HEADER_LEN + area)
+            .ok_or_else(truncated)?;
+        let player = bytes
+            .get(HEADER_LEN + area..HEADER_LEN + 2 * area)
+            .ok_or_else(truncated)?;
+
+        let mut rest = &bytes[HEADER_LEN + 2 * area..];
+        let mut next_string = |required: bool| -> Result<Vec<u8>, FormatError> {
+            match rest.iter().position(|byte| *byte == 0) {
+                Some(end) => {
+                    let text = rest[..end].to_vec();
+                    rest = &rest[end + 1..];
+                    Ok(text)
+                }
+                None if !required => Ok(std::mem::take(&mut rest).to_vec()),
+                None => Err(truncated()),
+            }
+        };
+        let title = next_string(true)?;
+        let author = next_string(true)?;
+        let copyright = next_string(true)?;
+        let clues = (0..clue_count)
+            .map(|_| next_string(true))
+            .collect::<Result<Vec<_>, _>>()?;
+        let notes = next_string(false)?;
+        let strings = Strings {
+            title,
+            author,
+            copyright,
+            clues,
+            notes,
+        };
+
+        let checksums = Checksums::compute(&header[0x2C..HEADER_LEN], solution, player, &strings);
+        if checksums.cib != read_u16(0x0E) {
+            return Err(FormatError::ChecksumMismatch("header".to_string()));
+        }
+        if checksums.global != read_u16(0x00) {
+            return Err(FormatError::ChecksumMismatch("file".to_string()));
+        }
+        if checksums.masked_low != header[0x10..0x14] || checksums.masked_high != header[0x14..0x18]
+        {
+            return Err(FormatError::ChecksumMismatch("masked".to_string()));
+        }
+
+        let board: Vec<Vec<char>> = solution
+            .chunks(width.max(1))
+            .map(|row| {
+                row.iter()
+                    .map(|byte| if *byte == BLOCK { ' ' } else { *byte as char })
+                    .collect()
+            })
+            .collect();
+
+        let runs = letter_runs(&board).len();
+        if runs != clue_count {
+            return Err(FormatError::InvalidData(format!(
+                "the board has {runs} words but the file has {clue_count} clues"
+            )));
+        }
+
+        let mut clues = strings.clues.iter().map(|clue| decode(clue));
+        let grid = OwnedGrid::from_board(board, |_| clues.next().unwrap_or_default())?;
+        let metadata = Metadata {
+            title: decode(&strings.title),
+            author: decode(&strings.author),
+            copyright: decode(&strings.copyright),
+            notes: decode(&strings.notes),
+        };
+
+        Ok((grid, metadata))
+    }
+}
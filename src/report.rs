@@ -0,0 +1,99 @@
+//! This module defines `GenerationReport`, which describes what happened during a
+//! generation run: which of the requested words did not make it into the puzzle and
+//! why, and how much searching was needed.
+
+use std::fmt::Display;
+
+use crate::connectivity::Island;
+
+/// `DropReason` explains why a requested word is missing from the generated grid.
+///
+/// # Examples
+///
+/// ```
+/// use crossword_puzzle::generate_with_report;
+/// use crossword_puzzle::options::GeneratorOptions;
+/// use crossword_puzzle::report::DropReason;
+///
+/// // "CONSECTETUR" shares letters with the board, but is too long for it.
+/// let words = &["LOREM", "IPSUM", "DOLOR", "CONSECTETUR"];
+/// let (_, report) = generate_with_report(&GeneratorOptions::new().max_size(7, 7), words).unwrap();
+/// assert_eq!(report.unplaced[0].answer, "CONSECTETUR");
+/// assert_eq!(report.unplaced[0].reason, DropReason::NoValidCrossing);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum DropReason {
+    /// The word shares no letter with any other requested word, so it could never cross one.
+    /// Such words are removed by `eliminate_entries` before the search starts.
+    NoSharedLetters,
+    /// None of the letters of the word appeared on the board when it was last tried,
+    /// so there was nothing for it to cross.
+    NoLetterOnBoard,
+    /// The word had letters in common with the board when it was last tried, but every
+    /// possible crossing was blocked.
+    NoValidCrossing,
+    /// The word was still waiting to be placed when the `max_words` limit was reached.
+    WordLimitReached,
+    /// The word was still waiting to be placed when the `timeout` or `max_nodes` budget
+    /// of the search ran out.
+    BudgetExhausted,
+}
+
+impl Display for DropReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Self::NoSharedLetters => write!(f, "no shared letters with other words"),
+            Self::NoLetterOnBoard => write!(f, "no letter in common with the board"),
+            Self::NoValidCrossing => write!(f, "no valid crossing on the board"),
+            Self::WordLimitReached => write!(f, "word limit reached"),
+            Self::BudgetExhausted => write!(f, "search budget exhausted"),
+        }
+    }
+}
+
+/// `UnplacedWord` is a requested word that does not appear in the generated grid.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct UnplacedWord<'a> {
+    /// The answer that was not placed.
+    pub answer: &'a str,
+    /// The clue of the answer that was not placed.
+    pub clue: &'a str,
+    /// Why the answer was not placed.
+    pub reason: DropReason,
+}
+
+/// `GenerationReport` summarises a generation run.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GenerationReport<'a> {
+    /// The requested words missing from the grid, in the order they were given up on.
+    pub unplaced: Vec<UnplacedWord<'a>>,
+    /// The number of placements added to a grid during the search. Each one is a node of
+    /// the search, counted against the `max_nodes` budget.
+    pub attempts: usize,
+    /// The number of times the search abandoned a placement to try another one.
+    pub backtracks: usize,
+    /// Whether the search stopped because its `timeout` or `max_nodes` budget ran out.
+    pub budget_exhausted: bool,
+    /// The islands of the grid the search ended with, when that grid was rejected because
+    /// `require_connected` is set and its words do not form a single connected component.
+    pub islands: Vec<Island>,
+}
+
+impl GenerationReport<'_> {
+    /// Returns `true` if every requested word was placed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::report::GenerationReport;
+    ///
+    /// assert!(GenerationReport::default().is_complete());
+    /// ```
+    pub fn is_complete(&self) -> bool {
+        self.unplaced.is_empty()
+    }
+}
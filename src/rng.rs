@@ -0,0 +1,39 @@
+//! A small, dependency-free pseudo-random number generator used to vary layouts.
+//! It implements SplitMix64, which is fast, has a 64-bit state that is trivial to
+//! seed, and produces the same sequence on every platform for a given seed.
+
+/// `Rng` is a SplitMix64 pseudo-random number generator.
+#[derive(Debug, Clone)]
+pub(crate) struct Rng {
+    /// The internal state, advanced on every call to `next_u64`.
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a new `Rng` from a seed.
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the next pseudo-random `u64`.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a pseudo-random index in `0..bound`. `bound` must be greater than zero.
+    pub(crate) fn next_below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    /// Shuffles a slice in place using the Fisher-Yates algorithm.
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.next_below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! This module defines `LayoutScore`, a set of quality measures for a generated `Grid`,
+//! and `Layout`, which pairs a grid with its score. Scores are used to rank several
+//! candidate layouts of the same word list against each other.
+
+use std::cmp::Ordering;
+
+use crate::Grid;
+
+/// `LayoutScore` describes the quality of a crossword layout.
+///
+/// Scores are ordered so that a greater score is a better layout: more placed words
+/// first, then more crossings, then a higher fill density, then an aspect ratio closer
+/// to square.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LayoutScore {
+    /// The number of words placed on the grid.
+    pub words_placed: usize,
+    /// The number of cells shared by two words.
+    pub crossings: usize,
+    /// The ratio of cells holding a letter to the total area of the board, between 0 and 1.
+    pub density: f64,
+    /// The ratio of the shorter board side to the longer one, between 0 and 1.
+    /// A square board has an aspect ratio of 1.
+    pub aspect_ratio: f64,
+}
+
+impl PartialOrd for LayoutScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(
+            self.words_placed
+                .cmp(&other.words_placed)
+                .then(self.crossings.cmp(&other.crossings))
+                .then(self.density.total_cmp(&other.density))
+                .then(self.aspect_ratio.total_cmp(&other.aspect_ratio)),
+        )
+    }
+}
+
+/// `Layout` pairs a generated `Grid` with its `LayoutScore`.
+#[derive(Debug, Clone)]
+pub struct Layout<'a> {
+    /// The generated grid.
+    pub grid: Grid<'a>,
+    /// The score of the grid.
+    pub score: LayoutScore,
+}
+
+impl<'a> From<Grid<'a>> for Layout<'a> {
+    /// Scores a `Grid` and wraps it in a `Layout`.
+    fn from(grid: Grid<'a>) -> Self {
+        let score = grid.score();
+        Self { grid, score }
+    }
+}
+
+impl Grid<'_> {
+    /// Computes the `LayoutScore` of the grid.
+    ///
+    /// # Returns
+    ///
+    /// A `LayoutScore` with the number of placed words, the number of crossings,
+    /// the fill density and the aspect ratio of the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{Grid, word::{Word, Direction}};
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.add_word(Word::value("", 'T', "EST").unwrap().direction(Direction::Horizontal)).unwrap();
+    /// grid.add_word(Word::value("", 'T', "OP").unwrap().position(3, 0).direction(Direction::Vertical)).unwrap();
+    ///
+    /// let score = grid.score();
+    /// assert_eq!(score.words_placed, 2);
+    /// assert_eq!(score.crossings, 1);
+    /// // 6 letters on a 4x3 board.
+    /// assert_eq!(score.density, 0.5);
+    /// assert_eq!(score.aspect_ratio, 0.75);
+    /// ```
+    pub fn score(&self) -> LayoutScore {
+        let height = self.board.len();
+        let width = self.board.first().map_or(0, |row| row.len());
+        let letters = self.board.iter().flatten().filter(|ch| **ch != ' ').count();
+        let word_cells: usize = self.words.iter().map(|word| word.segment.len()).sum();
+        let area = width * height;
+
+        LayoutScore {
+            words_placed: self.words.len(),
+            crossings: word_cells.saturating_sub(letters),
+            density: if area == 0 {
+                0.0
+            } else {
+                letters as f64 / area as f64
+            },
+            aspect_ratio: if width.max(height) == 0 {
+                0.0
+            } else {
+                width.min(height) as f64 / width.max(height) as f64
+            },
+        }
+    }
+}
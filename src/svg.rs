@@ -0,0 +1,358 @@
+//! This module renders a `Grid` as a standalone SVG image for publishing on the web or in
+//! print. The output is plain SVG 1.1 markup: it uses only generic font families, embeds
+//! no external resources and needs no network access or installed fonts to be valid.
+
+use crate::numbering::Clue;
+use crate::owned::OwnedGrid;
+use crate::Grid;
+
+/// The empty space left around the grid and the clue list, in pixels.
+const MARGIN: usize = 8;
+
+/// `BlankCells` determines how the empty cells of the board are drawn.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum BlankCells {
+    /// Draws empty cells as shaded squares, like the blocks of a printed crossword.
+    #[default]
+    Shaded,
+    /// Leaves empty cells out, so only the cells of the words are drawn.
+    Omitted,
+}
+
+/// `SvgOptions` configures the SVG rendering performed by `Grid::to_svg`.
+///
+/// The default options draw 32 pixel cells with shaded blank cells in a sans-serif font,
+/// without the solution and without the clue list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    /// The width and height of a cell, in pixels.
+    pub cell_size: usize,
+    /// The CSS font family of all text. Generic families such as `sans-serif` or `serif`
+    /// are always available.
+    pub font_family: String,
+    /// The font size of the letters, or `None` for 60% of the cell size.
+    pub letter_font_size: Option<usize>,
+    /// The font size of the clue numbers, or `None` for 30% of the cell size.
+    pub number_font_size: Option<usize>,
+    /// The font size of the clue list.
+    pub clue_font_size: usize,
+    /// Whether the letters of the solution are drawn in the cells.
+    pub show_solution: bool,
+    /// Whether the across and down clue lists are drawn below the grid.
+    pub show_clues: bool,
+    /// How the empty cells of the board are drawn.
+    pub blank_cells: BlankCells,
+}
+
+impl Default for SvgOptions {
+    /// Creates the default `SvgOptions`.
+    fn default() -> Self {
+        Self {
+            cell_size: 32,
+            font_family: "sans-serif".to_string(),
+            letter_font_size: None,
+            number_font_size: None,
+            clue_font_size: 14,
+            show_solution: false,
+            show_clues: false,
+            blank_cells: BlankCells::default(),
+        }
+    }
+}
+
+impl SvgOptions {
+    /// Creates a new `SvgOptions` instance with the default settings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::svg::SvgOptions;
+    ///
+    /// let options = SvgOptions::new();
+    /// assert_eq!(options.cell_size, 32);
+    /// assert!(!options.show_solution);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the width and height of a cell, in pixels. Values below `8` are treated as `8`.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::svg::SvgOptions;
+    ///
+    /// let options = SvgOptions::new().cell_size(40);
+    /// assert_eq!(options.cell_size, 40);
+    /// ```
+    pub fn cell_size(mut self, cell_size: usize) -> Self {
+        self.cell_size = cell_size.max(8);
+        self
+    }
+
+    /// Sets the CSS font family of all text.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn font_family(mut self, font_family: impl Into<String>) -> Self {
+        self.font_family = font_family.into();
+        self
+    }
+
+    /// Sets the font size of the letters.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn letter_font_size(mut self, size: usize) -> Self {
+        self.letter_font_size = Some(size);
+        self
+    }
+
+    /// Sets the font size of the clue numbers.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn number_font_size(mut self, size: usize) -> Self {
+        self.number_font_size = Some(size);
+        self
+    }
+
+    /// Sets the font size of the clue list.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn clue_font_size(mut self, size: usize) -> Self {
+        self.clue_font_size = size;
+        self
+    }
+
+    /// Sets whether the letters of the solution are drawn in the cells.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn show_solution(mut self, show_solution: bool) -> Self {
+        self.show_solution = show_solution;
+        self
+    }
+
+    /// Sets whether the across and down clue lists are drawn below the grid.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    pub fn show_clues(mut self, show_clues: bool) -> Self {
+        self.show_clues = show_clues;
+        self
+    }
+
+    /// Sets how the empty cells of the board are drawn.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::svg::{BlankCells, SvgOptions};
+    ///
+    /// let options = SvgOptions::new().blank_cells(BlankCells::Omitted);
+    /// assert_eq!(options.blank_cells, BlankCells::Omitted);
+    /// ```
+    pub fn blank_cells(mut self, blank_cells: BlankCells) -> Self {
+        self.blank_cells = blank_cells;
+        self
+    }
+}
+
+/// Escapes the characters of `text` that have a special meaning in XML.
+pub(crate) fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+/// Breaks `text` into lines of at most `width` characters, at spaces where possible.
+pub(crate) fn wrap(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+        let line_len = line.chars().count();
+        if line_len > 0 && line_len + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.extend(word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Formats a clue for a clue list, as `number. clue (length)`.
+pub(crate) fn clue_line(clue: &Clue) -> String {
+    format!("{}. {} ({})", clue.number, clue.clue, clue.length)
+}
+
+impl Grid<'_> {
+    /// Renders the grid as an SVG image.
+    ///
+    /// Every cell holding a letter is drawn as a white square, with its clue number in the
+    /// top-left corner if a word starts there. Empty cells are shaded or omitted depending
+    /// on `options.blank_cells`. The letters are only drawn if `options.show_solution` is
+    /// set, and the across and down clue lists are drawn below the grid in two columns if
+    /// `options.show_clues` is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The `SvgOptions` controlling sizes, fonts and what is drawn.
+    ///
+    /// # Returns
+    ///
+    /// A `String` holding a complete SVG document.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::{generate_with_clues, PuzzleEntry};
+    /// use crossword_puzzle::svg::{BlankCells, SvgOptions};
+    ///
+    /// let entries = [PuzzleEntry::new("TEST", "An exam"), PuzzleEntry::new("TOP", "Peak & summit")];
+    /// let grid = generate_with_clues(&entries).unwrap().unwrap();
+    ///
+    /// let puzzle = grid.to_svg(&SvgOptions::new());
+    /// assert!(puzzle.starts_with("<svg"));
+    /// assert!(puzzle.trim_end().ends_with("</svg>"));
+    /// assert!(!puzzle.contains(">P</text>"));
+    ///
+    /// let answer_key = grid.to_svg(
+    ///     &SvgOptions::new()
+    ///         .show_solution(true)
+    ///         .show_clues(true)
+    ///         .blank_cells(BlankCells::Omitted),
+    /// );
+    /// assert!(answer_key.contains(">P</text>"));
+    /// assert!(answer_key.contains("Peak &amp; summit"));
+    /// ```
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        let cell = options.cell_size;
+        let letter_size = options.letter_font_size.unwrap_or(cell * 3 / 5);
+        let number_size = options.number_font_size.unwrap_or(cell * 3 / 10);
+        let rows = self.board.len();
+        let columns = self.board.first().map_or(0, |row| row.len());
+        let grid_width = columns * cell;
+        let grid_height = rows * cell;
+
+        let clues = self.clues();
+        let clue_size = options.clue_font_size.max(1);
+        let line_height = clue_size * 7 / 5;
+        let column_width = (grid_width / 2).max(clue_size * 16);
+        let chars_per_line = column_width * 9 / 5 / clue_size;
+        let clue_columns: Vec<(&str, Vec<String>)> =
+            [("Across", &clues.across), ("Down", &clues.down)]
+                .into_iter()
+                .map(|(title, list)| {
+                    let lines = list
+                        .iter()
+                        .flat_map(|clue| wrap(&clue_line(clue), chars_per_line))
+                        .collect();
+                    (title, lines)
+                })
+                .collect();
+
+        let mut width = grid_width + 2 * MARGIN;
+        let mut height = grid_height + 2 * MARGIN;
+        if options.show_clues {
+            width = width.max(2 * column_width + 3 * MARGIN);
+            let longest = clue_columns
+                .iter()
+                .map(|(_, lines)| lines.len())
+                .max()
+                .unwrap_or(0);
+            height += MARGIN + (longest + 1) * line_height;
+        }
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" font-family=\"{}\">\n",
+            escape_xml(&options.font_family)
+        );
+        svg.push_str(&format!(
+            "<rect width=\"{width}\" height=\"{height}\" fill=\"#ffffff\"/>\n"
+        ));
+
+        for (y, row) in self.board.iter().enumerate() {
+            for (x, ch) in row.iter().enumerate() {
+                let left = MARGIN + x * cell;
+                let top = MARGIN + y * cell;
+                let fill = match (*ch == ' ', options.blank_cells) {
+                    (true, BlankCells::Omitted) => continue,
+                    (true, BlankCells::Shaded) => "#333333",
+                    (false, _) => "#ffffff",
+                };
+                svg.push_str(&format!(
+                    "<rect x=\"{left}\" y=\"{top}\" width=\"{cell}\" height=\"{cell}\" fill=\"{fill}\" stroke=\"#000000\" stroke-width=\"1\"/>\n"
+                ));
+                if *ch != ' ' && options.show_solution {
+                    svg.push_str(&format!(
+                        "<text x=\"{}\" y=\"{}\" font-size=\"{letter_size}\" text-anchor=\"middle\" dominant-baseline=\"central\">{}</text>\n",
+                        left + cell / 2,
+                        top + cell * 11 / 20,
+                        escape_xml(&ch.to_string())
+                    ));
+                }
+            }
+        }
+
+        for (number, position) in self.numbers() {
+            svg.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" font-size=\"{number_size}\">{number}</text>\n",
+                MARGIN + position.x * cell + 2,
+                MARGIN + position.y * cell + number_size + 1
+            ));
+        }
+
+        if options.show_clues {
+            let top = MARGIN + grid_height + MARGIN;
+            for (i, (title, lines)) in clue_columns.iter().enumerate() {
+                let left = MARGIN + i * (column_width + MARGIN);
+                svg.push_str(&format!(
+                    "<text x=\"{left}\" y=\"{}\" font-size=\"{clue_size}\" font-weight=\"bold\">{title}</text>\n",
+                    top + clue_size
+                ));
+                for (j, line) in lines.iter().enumerate() {
+                    svg.push_str(&format!(
+                        "<text x=\"{left}\" y=\"{}\" font-size=\"{clue_size}\">{}</text>\n",
+                        top + clue_size + (j + 1) * line_height,
+                        escape_xml(line)
+                    ));
+                }
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+impl OwnedGrid {
+    /// Renders the grid as an SVG image (see `Grid::to_svg`).
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        self.as_grid().to_svg(options)
+    }
+}
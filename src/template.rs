@@ -0,0 +1,573 @@
+//! This module implements American-style puzzles: a fixed `Template` of open and blocked
+//! cells whose slots are filled from a word list by an autofill solver.
+//!
+//! Unlike the freeform layouts built by `generate`, the shape of the puzzle is given up
+//! front, and every run of two or more open cells must hold a word. The solver fills the
+//! most constrained slot first and backtracks when a slot has no candidate left. The filled
+//! puzzle is returned as an ordinary `Grid`, with blocked cells left empty.
+
+use std::fmt::Display;
+use std::time::Instant;
+
+use crate::dictionary::Dictionary;
+use crate::error::{Error, GridError, WordError};
+use crate::options::GeneratorOptions;
+use crate::rng::Rng;
+use crate::word::{Direction, Position, WordRepr};
+use crate::Grid;
+
+/// `Cell` is a single square of a `Template`.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum Cell {
+    /// A white square that must hold a letter.
+    #[default]
+    Open,
+    /// A black square that holds no letter.
+    Block,
+}
+
+/// `Slot` is a run of two or more open cells of a `Template`, which must hold one word.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Slot {
+    /// The first cell of the slot.
+    pub position: Position,
+    /// The direction of the slot.
+    pub direction: Direction,
+    /// The number of cells of the slot.
+    pub length: usize,
+}
+
+impl Slot {
+    /// Returns the `Position`s of the cells of the slot, from first to last.
+    pub fn positions(&self) -> Vec<Position> {
+        (0..self.length)
+            .map(|i| match self.direction {
+                Direction::Vertical => Position {
+                    x: self.position.x,
+                    y: self.position.y + i,
+                },
+                _ => Position {
+                    x: self.position.x + i,
+                    y: self.position.y,
+                },
+            })
+            .collect()
+    }
+}
+
+/// `Template` is the shape of an American-style puzzle: a rectangle of open and blocked cells.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    /// The cells of the template, row by row.
+    pub cells: Vec<Vec<Cell>>,
+}
+
+impl Display for Template {
+    /// Formats the template with `#` for blocked cells and `.` for open cells, one row per line.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.cells.iter() {
+            for cell in row.iter() {
+                write!(f, "{}", if *cell == Cell::Block { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A level of the explicit search stack used by the autofill solver.
+struct Frame {
+    /// The index of the slot being filled at this level.
+    slot: usize,
+    /// The indices of the candidate dictionary entries for the slot, in the order they are tried.
+    candidates: Vec<usize>,
+    /// The index of the next candidate to try.
+    next: usize,
+    /// The cells that the candidate currently applied filled in, to be cleared on undo.
+    filled: Vec<Position>,
+}
+
+impl Template {
+    /// Creates a new `Template` of `width` columns and `height` rows with every cell open.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::template::Template;
+    ///
+    /// let template = Template::new(3, 2);
+    /// assert_eq!(template.width(), 3);
+    /// assert_eq!(template.height(), 2);
+    /// assert_eq!(template.to_string(), "...\n...\n");
+    /// ```
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            cells: vec![vec![Cell::Open; width]; height],
+        }
+    }
+
+    /// Blocks the cell at column `x` and row `y`. Cells outside the template are ignored.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::template::Template;
+    ///
+    /// let template = Template::new(3, 3).block(1, 1);
+    /// assert_eq!(template.to_string(), "...\n.#.\n...\n");
+    /// ```
+    pub fn block(mut self, x: usize, y: usize) -> Self {
+        if let Some(cell) = self.cells.get_mut(y).and_then(|row| row.get_mut(x)) {
+            *cell = Cell::Block;
+        }
+        self
+    }
+
+    /// Parses a template from text, one row per line, with `#` for blocked cells and `.`
+    /// or `_` for open cells. Empty lines and surrounding whitespace are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The rows of the template.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Template)` if the text describes a rectangular template.
+    /// - `Err(GridError)` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GridError::MalformedBoard` if the template is empty, its rows differ in
+    /// length, or it contains a character other than `#`, `.` and `_`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::template::{Cell, Template};
+    ///
+    /// let template = Template::parse("..#\n...\n#..").unwrap();
+    /// assert_eq!(template.cells[0][2], Cell::Block);
+    /// assert_eq!(template.cells[1][1], Cell::Open);
+    ///
+    /// assert!(Template::parse("...\n..").is_err());
+    /// assert!(Template::parse("..X").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, GridError> {
+        let cells = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.chars()
+                    .map(|ch| match ch {
+                        '#' => Ok(Cell::Block),
+                        '.' | '_' => Ok(Cell::Open),
+                        _ => Err(GridError::MalformedBoard(format!(
+                            "unexpected character '{ch}' in the template"
+                        ))),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let width = cells.first().map_or(0, |row| row.len());
+        if width == 0 {
+            return Err(GridError::MalformedBoard(
+                "the template is empty".to_string(),
+            ));
+        }
+        if cells.iter().any(|row| row.len() != width) {
+            return Err(GridError::MalformedBoard(
+                "the rows of the template differ in length".to_string(),
+            ));
+        }
+        Ok(Self { cells })
+    }
+
+    /// Returns the number of columns of the template.
+    pub fn width(&self) -> usize {
+        self.cells.first().map_or(0, |row| row.len())
+    }
+
+    /// Returns the number of rows of the template.
+    pub fn height(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns `true` if the cell at `position` exists and is open.
+    pub fn is_open(&self, position: Position) -> bool {
+        self.cells
+            .get(position.y)
+            .and_then(|row| row.get(position.x))
+            .is_some_and(|cell| *cell == Cell::Open)
+    }
+
+    /// Returns the slots of the template: every run of two or more open cells in a row or
+    /// column, in reading order of their first cell, with the across slot before the down
+    /// slot when both start in the same cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::template::Template;
+    /// use crossword_puzzle::word::{Direction, Position};
+    ///
+    /// let template = Template::parse("...\n.#.\n...").unwrap();
+    /// let slots = template.slots();
+    ///
+    /// assert_eq!(slots.len(), 4);
+    /// assert_eq!(slots[0].position, Position { x: 0, y: 0 });
+    /// assert_eq!(slots[0].direction, Direction::Horizontal);
+    /// assert_eq!(slots[1].direction, Direction::Vertical);
+    /// assert!(slots.iter().all(|slot| slot.length == 3));
+    /// ```
+    pub fn slots(&self) -> Vec<Slot> {
+        let mut slots = Vec::new();
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let open = |x: usize, y: usize| self.is_open(Position { x, y });
+                if !open(x, y) {
+                    continue;
+                }
+                let position = Position { x, y };
+                if (x == 0 || !open(x - 1, y)) && open(x + 1, y) {
+                    slots.push(Slot {
+                        position,
+                        direction: Direction::Horizontal,
+                        length: (x..).take_while(|x| open(*x, y)).count(),
+                    });
+                }
+                if (y == 0 || !open(x, y - 1)) && open(x, y + 1) {
+                    slots.push(Slot {
+                        position,
+                        direction: Direction::Vertical,
+                        length: (y..).take_while(|y| open(x, *y)).count(),
+                    });
+                }
+            }
+        }
+        slots
+    }
+
+    /// Fills every slot of the template with a word from `words`, using the default
+    /// `GeneratorOptions` (see `Template::autofill_with`).
+    ///
+    /// # Arguments
+    ///
+    /// * `words` - The word list to fill the template from.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(Grid))` with every slot filled.
+    /// - `Ok(None)` if the template cannot be filled from the word list.
+    /// - `Err(Error)` if an error occurs during the fill.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::WordError` if any word contains lowercase characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::template::Template;
+    ///
+    /// let template = Template::parse("...\n.#.\n...").unwrap();
+    /// let words = &["ABA", "ADA", "API", "ARI", "IBU"];
+    ///
+    /// let grid = template.autofill(words).unwrap().unwrap();
+    /// assert_eq!(grid.words.len(), 4);
+    /// assert!(grid.validate().is_ok());
+    /// assert_eq!(grid.board[1][1], ' ');
+    /// ```
+    pub fn autofill<'a>(&self, words: &[&'a str]) -> Result<Option<Grid<'a>>, Error> {
+        self.autofill_with(&GeneratorOptions::default(), words)
+    }
+
+    /// Fills every slot of the template with a word from `words`.
+    ///
+    /// The solver fills one slot at a time. It always picks the open slot with the fewest
+    /// words that still match the letters crossing it, so slots that are nearly decided
+    /// are filled first and a slot without any candidate is found as soon as it appears.
+    /// In that case, the solver backtracks and tries the next candidate of the slot filled
+    /// before. Every word is used at most once.
+    ///
+    /// Of the `options`, only `seed`, `timeout` and `max_nodes` are used. With a seed, the
+    /// candidates of each slot are tried in a seed-dependent order, so different seeds give
+    /// different fills. When the `timeout` or `max_nodes` budget runs out, the fill is
+    /// abandoned and `Ok(None)` is returned.
+    ///
+    /// Open cells that belong to no slot are left empty, as they cannot be checked by any
+    /// word. The filled slots are returned as the `Word`s of a `Grid` without clues.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The `GeneratorOptions` providing the seed and the search budget.
+    /// * `words` - The word list to fill the template from.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(Grid))` with every slot filled.
+    /// - `Ok(None)` if the template has no slots, cannot be filled from the word list, or
+    ///   the search budget ran out.
+    /// - `Err(Error)` if an error occurs during the fill.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::WordError` if any word contains lowercase characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::options::GeneratorOptions;
+    /// use crossword_puzzle::template::Template;
+    ///
+    /// let text = std::fs::read_to_string("example/indonesian_dictionary_words.txt").unwrap();
+    /// let words: Vec<&str> = text.lines().collect();
+    /// let template = Template::parse("
+    ///     .....
+    ///     .#.#.
+    ///     .....
+    ///     .#.#.
+    ///     .....
+    /// ").unwrap();
+    ///
+    /// let options = GeneratorOptions::new().seed(7);
+    /// let grid = template.autofill_with(&options, &words).unwrap().unwrap();
+    /// assert_eq!(grid.words.len(), template.slots().len());
+    /// assert!(grid.validate().is_ok());
+    /// ```
+    pub fn autofill_with<'a>(
+        &self,
+        options: &GeneratorOptions,
+        words: &[&'a str],
+    ) -> Result<Option<Grid<'a>>, Error> {
+        let dictionary = Dictionary::from_words(words);
+        let Some(fill) = self.fill(options, &dictionary)? else {
+            return Ok(None);
+        };
+        let answers = fill
+            .into_iter()
+            .map(|(slot, entry)| {
+                let word = &dictionary.entries()[entry].word;
+                let answer = words
+                    .iter()
+                    .map(|word| word.trim())
+                    .find(|answer| answer == word)
+                    .expect("the dictionary only holds the given words");
+                (slot, answer)
+            })
+            .collect();
+        self.build_grid(answers).map(Some)
+    }
+
+    /// Fills every slot of the template with a word from `dictionary`.
+    ///
+    /// This works like `Template::autofill_with`, but takes the words from an indexed
+    /// `Dictionary`, which can be loaded once and reused for many templates. Without a
+    /// seed, the candidates of each slot are tried from the highest score to the lowest,
+    /// so the fill prefers the best words of the dictionary.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - The `GeneratorOptions` providing the seed and the search budget.
+    /// * `dictionary` - The `Dictionary` to fill the template from.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(Grid))` with every slot filled.
+    /// - `Ok(None)` if the template has no slots, cannot be filled from the dictionary, or
+    ///   the search budget ran out.
+    /// - `Err(Error)` if an error occurs during the fill.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error::WordError` if any word of the dictionary contains lowercase characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::dictionary::Dictionary;
+    /// use crossword_puzzle::options::GeneratorOptions;
+    /// use crossword_puzzle::template::Template;
+    ///
+    /// let dictionary = Dictionary::parse("aba;1\nada;1\napi;1\nari;1\nibu;1\nana;9").unwrap();
+    /// let template = Template::parse("...\n.#.\n...").unwrap();
+    ///
+    /// let grid = template
+    ///     .autofill_from(&GeneratorOptions::new(), &dictionary)
+    ///     .unwrap()
+    ///     .unwrap();
+    /// assert_eq!(grid.words.len(), 4);
+    /// // ANA has the highest score, so it is tried first.
+    /// assert!(grid.words.iter().any(|word| word.segment.full_word_str() == "ANA"));
+    /// ```
+    pub fn autofill_from<'a>(
+        &self,
+        options: &GeneratorOptions,
+        dictionary: &'a Dictionary,
+    ) -> Result<Option<Grid<'a>>, Error> {
+        let Some(fill) = self.fill(options, dictionary)? else {
+            return Ok(None);
+        };
+        let answers = fill
+            .into_iter()
+            .map(|(slot, entry)| (slot, dictionary.entries()[entry].word.as_str()))
+            .collect();
+        self.build_grid(answers).map(Some)
+    }
+
+    /// Builds the `Grid` of a filled template from the answer of every slot.
+    fn build_grid<'a>(&self, answers: Vec<(Slot, &'a str)>) -> Result<Grid<'a>, Error> {
+        let mut board = vec![vec![' '; self.width()]; self.height()];
+        let reprs: Vec<WordRepr<'a>> = answers
+            .into_iter()
+            .map(|(slot, answer)| {
+                for (pos, ch) in slot.positions().into_iter().zip(answer.chars()) {
+                    board[pos.y][pos.x] = ch;
+                }
+                WordRepr {
+                    answer,
+                    clue: "",
+                    display: "",
+                    position: slot.position,
+                    orientation: slot.direction,
+                }
+            })
+            .collect();
+        Ok(Grid::from_word_reprs(&reprs, board)?)
+    }
+
+    /// The solver behind `Template::autofill_with` and `Template::autofill_from`.
+    ///
+    /// The search runs as a loop over an explicit stack of `Frame`s, like the generator
+    /// search. Candidates for a slot are looked up in the letter index of the dictionary
+    /// from the letters already placed in its cells.
+    ///
+    /// Returns the index of the dictionary entry chosen for every slot, or `None` if no
+    /// fill was found.
+    fn fill(
+        &self,
+        options: &GeneratorOptions,
+        dictionary: &Dictionary,
+    ) -> Result<Option<Vec<(Slot, usize)>>, Error> {
+        if dictionary
+            .entries()
+            .iter()
+            .any(|entry| entry.word.chars().any(char::is_lowercase))
+        {
+            return Err(Error::WordError(WordError::LowercaseCharactersInSegment));
+        }
+
+        let slots = self.slots();
+        if slots.is_empty() {
+            return Ok(None);
+        }
+        let slot_cells: Vec<Vec<Position>> = slots.iter().map(Slot::positions).collect();
+
+        let mut board: Vec<Vec<Option<char>>> = vec![vec![None; self.width()]; self.height()];
+        let mut assigned: Vec<Option<usize>> = vec![None; slots.len()];
+        let mut used = vec![false; dictionary.len()];
+        let mut rng = options.seed.map(Rng::new);
+        let mut stack: Vec<Frame> = Vec::new();
+        let started = Instant::now();
+        let mut nodes = 0;
+
+        let candidates = |board: &[Vec<Option<char>>], used: &[bool], slot: usize| {
+            let pattern: Vec<Option<char>> = slot_cells[slot]
+                .iter()
+                .map(|pos| board[pos.y][pos.x])
+                .collect();
+            let mut indices = dictionary.pattern_indices(&pattern);
+            indices.retain(|index| !used[*index]);
+            indices
+        };
+
+        loop {
+            let out_of_time = options
+                .timeout
+                .is_some_and(|timeout| started.elapsed() >= timeout);
+            let out_of_nodes = options
+                .max_nodes
+                .is_some_and(|max_nodes| nodes >= max_nodes);
+            if out_of_time || out_of_nodes {
+                return Ok(None);
+            }
+
+            // Pick the open slot with the fewest matching words, preferring longer slots.
+            let mut best: Option<(usize, Vec<usize>)> = None;
+            for slot in (0..slots.len()).filter(|slot| assigned[*slot].is_none()) {
+                let matching = candidates(&board, &used, slot);
+                let count = matching.len();
+                let better = best.as_ref().is_none_or(|(best_slot, best_matching)| {
+                    count < best_matching.len()
+                        || (count == best_matching.len()
+                            && slots[slot].length > slots[*best_slot].length)
+                });
+                if better {
+                    best = Some((slot, matching));
+                }
+                if count == 0 {
+                    break;
+                }
+            }
+
+            let Some((slot, mut candidates)) = best else {
+                return Ok(Some(
+                    slots
+                        .iter()
+                        .zip(assigned.iter())
+                        .map(|(slot, entry)| (*slot, entry.expect("every slot is filled")))
+                        .collect(),
+                ));
+            };
+
+            if let Some(rng) = rng.as_mut() {
+                rng.shuffle(&mut candidates);
+            }
+            stack.push(Frame {
+                slot,
+                candidates,
+                next: 0,
+                filled: Vec::new(),
+            });
+
+            // Apply the next untried candidate of the innermost frame. A frame whose
+            // candidates are all used up is a dead end: it is removed and the search
+            // resumes with the next candidate of the frame before it.
+            loop {
+                let Some(frame) = stack.last_mut() else {
+                    return Ok(None);
+                };
+
+                if let Some(entry) = assigned[frame.slot].take() {
+                    used[entry] = false;
+                    for pos in frame.filled.drain(..) {
+                        board[pos.y][pos.x] = None;
+                    }
+                }
+
+                if frame.next < frame.candidates.len() {
+                    let entry = frame.candidates[frame.next];
+                    frame.next += 1;
+                    for (pos, ch) in slot_cells[frame.slot]
+                        .iter()
+                        .zip(dictionary.letters(entry).iter())
+                    {
+                        if board[pos.y][pos.x].is_none() {
+                            board[pos.y][pos.x] = Some(*ch);
+                            frame.filled.push(*pos);
+                        }
+                    }
+                    assigned[frame.slot] = Some(entry);
+                    used[entry] = true;
+                    nodes += 1;
+                    break;
+                }
+
+                stack.pop();
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! This module implements `Grid::validate`, which checks a finished or imported grid as a
+//! whole.
+//!
+//! While generating, `Grid::is_valid_placement` only looks at the neighbours of the word
+//! being placed. A grid built by other means, such as one loaded from a file or assembled
+//! with `Grid::from_parts`, can still contain letters that run into each other. The
+//! validation reports every such problem as a `GridError` carrying the cells involved.
+
+use crate::error::GridError;
+use crate::numbering::letter_runs;
+use crate::owned::OwnedGrid;
+use crate::word::Direction;
+use crate::Grid;
+
+impl Grid<'_> {
+    /// Checks the whole grid and returns every problem found.
+    ///
+    /// The following checks are made, and their diagnostics are returned in this order:
+    /// - The board is not empty and all its rows have the same length (`MalformedBoard`).
+    ///   If this check fails, no other check is made.
+    /// - Every word has a direction (`InvalidDirection`), lies within the board
+    ///   (`OutOfBounds`) and matches the letters on the board (`LetterMismatch`).
+    /// - No two words running in the same direction share a cell (`OverlappingWords`).
+    /// - Every run of two or more letters in a row or column of the board is a placed word
+    ///   with the same start cell, direction and length (`UnintendedWord`).
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the grid passes every check.
+    /// - `Err(Vec<GridError>)` with one diagnostic per problem found.
+    ///
+    /// # Errors
+    ///
+    /// Returns the list of `GridError`s described above if any check fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::error::GridError;
+    /// use crossword_puzzle::{generate, Grid, word::{Word, Direction, Position}};
+    ///
+    /// let grid = generate(&["LOREM", "IPSUM", "DOLOR", "SIT", "AMET"]).unwrap().unwrap();
+    /// assert!(grid.validate().is_ok());
+    ///
+    /// // The A of CAT sits above the N of NO, forming the unintended word AN.
+    /// let mut cat = Word::value("", 'C', "AT").unwrap().direction(Direction::Horizontal);
+    /// cat.update_position();
+    /// let mut to = Word::value("", 'T', "O").unwrap().position(2, 0).direction(Direction::Vertical);
+    /// to.update_position();
+    /// let mut no = Word::value("", 'N', "O").unwrap().position(1, 1).direction(Direction::Horizontal);
+    /// no.update_position();
+    /// let board = vec![vec!['C', 'A', 'T'], vec![' ', 'N', 'O']];
+    /// let grid = Grid::from_parts(vec![cat, to, no], board).unwrap();
+    ///
+    /// let errors = grid.validate().unwrap_err();
+    /// assert_eq!(errors.len(), 1);
+    /// match &errors[0] {
+    ///     GridError::UnintendedWord { letters, position, direction } => {
+    ///         assert_eq!(letters, "AN");
+    ///         assert_eq!(*position, Position { x: 1, y: 0 });
+    ///         assert_eq!(*direction, Direction::Vertical);
+    ///     }
+    ///     other => panic!("unexpected diagnostic: {other}"),
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<GridError>> {
+        let width = self.board.first().map_or(0, |row| row.len());
+        if width == 0 {
+            return Err(vec![GridError::MalformedBoard(
+                "the board is empty".to_string(),
+            )]);
+        }
+        if self.board.iter().any(|row| row.len() != width) {
+            return Err(vec![GridError::MalformedBoard(
+                "the rows of the board differ in length".to_string(),
+            )]);
+        }
+
+        let mut errors = Vec::new();
+
+        for word in self.words.iter() {
+            if word.direction == Direction::NotSet {
+                errors.push(GridError::InvalidDirection(format!(
+                    "word {} has no direction",
+                    word.segment.full_word_str()
+                )));
+                continue;
+            }
+            for (pos, expected) in word.positions().into_iter().zip(word.segment.full_word()) {
+                match self.get_char(pos) {
+                    None => {
+                        errors.push(GridError::OutOfBounds {
+                            answer: word.segment.full_word_str(),
+                            position: pos,
+                        });
+                        break;
+                    }
+                    Some(found) if found != expected => {
+                        errors.push(GridError::LetterMismatch {
+                            answer: word.segment.full_word_str(),
+                            position: pos,
+                            expected,
+                            found,
+                        });
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        for (index, first) in self.words.iter().enumerate() {
+            for second in self.words[index + 1..].iter() {
+                if first.direction != second.direction || first.direction == Direction::NotSet {
+                    continue;
+                }
+                let cells = second.positions();
+                if let Some(position) = first.positions().into_iter().find(|p| cells.contains(p)) {
+                    errors.push(GridError::OverlappingWords {
+                        first: first.segment.full_word_str(),
+                        second: second.segment.full_word_str(),
+                        position,
+                        direction: first.direction,
+                    });
+                }
+            }
+        }
+
+        for run in letter_runs(&self.board) {
+            let is_placed = self.words.iter().any(|word| {
+                word.direction == run.direction
+                    && word.origin == run.position
+                    && word.segment.len() == run.length
+            });
+            if !is_placed {
+                errors.push(GridError::UnintendedWord {
+                    letters: run.letters(&self.board),
+                    position: run.position,
+                    direction: run.direction,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl OwnedGrid {
+    /// Checks the whole grid and returns every problem found (see `Grid::validate`).
+    ///
+    /// # Errors
+    ///
+    /// Returns a list of `GridError`s if any check fails.
+    pub fn validate(&self) -> Result<(), Vec<GridError>> {
+        self.as_grid().validate()
+    }
+}
@@ -6,13 +6,13 @@
 use std::fmt::Display;
 
 #[cfg(feature = "serde")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::error::WordError;
 
 /// `Direction` defines the possible orientations for a word within the crossword puzzle grid.
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Direction {
     /// Represents a horizontal orientation, where the word extends from left to right.
@@ -38,7 +38,7 @@ impl Display for Direction {
 /// `Position` represents the (x, y) coordinates of a cell on the crossword grid.
 /// `x` corresponds to the column index, and `y` corresponds to the row index.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Position {
     /// The x-coordinate (column index) of the position.
     pub x: usize,
@@ -62,8 +62,8 @@ pub struct Segment<'a> {
 impl<'a> Segment<'a> {
     /// Creates a new `Segment` instance.
     ///
-    /// This function validates the input to ensure that segments are not empty or whitespace-only,
-    /// and that they do not contain lowercase characters.
+    /// This function validates the input to ensure that segments are not empty, and that they
+    /// do not contain whitespace or lowercase characters.
     ///
     /// # Arguments
     ///
@@ -80,6 +80,8 @@ impl<'a> Segment<'a> {
     ///
     /// Returns a `WordError::EmptyOrWhitespaceSegment` if `prefix`, `suffix` are empty and `crossed` is whitespace.
     /// Returns a `WordError::LowercaseCharactersInSegment` if any part contains lowercase characters.
+    /// Returns a `WordError::WhitespaceInSegment` if any part contains whitespace, which marks
+    /// empty cells on the board.
     ///
     /// # Examples
     ///
@@ -94,6 +96,7 @@ impl<'a> Segment<'a> {
     /// // Example of an error
     /// let error_segment = Segment::new("", ' ', "");
     /// assert!(error_segment.is_err());
+    /// assert!(Segment::new("ICE", ' ', "CREAM").is_err());
     /// ```
     pub fn new(prefix: &'a str, crossed: char, suffix: &'a str) -> Result<Self, WordError> {
         if prefix.is_empty() && suffix.is_empty() && crossed.is_ascii_whitespace() {
@@ -107,6 +110,13 @@ impl<'a> Segment<'a> {
             return Err(WordError::LowercaseCharactersInSegment);
         }
 
+        if prefix.chars().any(char::is_whitespace)
+            || crossed.is_whitespace()
+            || suffix.chars().any(char::is_whitespace)
+        {
+            return Err(WordError::WhitespaceInSegment);
+        }
+
         Ok(Segment {
             prefix,
             crossed,
@@ -115,6 +125,52 @@ impl<'a> Segment<'a> {
     }
 }
 
+impl<'a> Segment<'a> {
+    /// Splits a word into a `Segment` that crosses at the given character index.
+    ///
+    /// The character at `index` becomes the `crossed` character, everything before it the
+    /// `prefix` and everything after it the `suffix`. The index counts characters, not bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The string slice of the full word.
+    /// * `index` - The character index of the crossing character.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Segment)` if the split segment is valid.
+    /// - `Err(WordError)` if `index` is outside of the word or the segment is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `WordError::CrossingIndexOutOfRange` if `word` has no character at `index`,
+    /// or any error of `Segment::new`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::word::Segment;
+    ///
+    /// let segment = Segment::split_at("KİRAZ", 1).unwrap();
+    /// assert_eq!(segment.prefix, "K");
+    /// assert_eq!(segment.crossed, 'İ');
+    /// assert_eq!(segment.suffix, "RAZ");
+    ///
+    /// assert!(Segment::split_at("KİRAZ", 5).is_err());
+    /// ```
+    pub fn split_at(word: &'a str, index: usize) -> Result<Self, WordError> {
+        let (byte_index, crossed) = word
+            .char_indices()
+            .nth(index)
+            .ok_or(WordError::CrossingIndexOutOfRange(index))?;
+        Segment::new(
+            &word[..byte_index],
+            crossed,
+            &word[byte_index + crossed.len_utf8()..],
+        )
+    }
+}
+
 impl Segment<'_> {
     /// Returns the full word by combining the `prefix`, `crossed` character, and `suffix`.
     /// The result is a `Vec<char>` representing the complete word.
@@ -162,6 +218,54 @@ impl Segment<'_> {
         }
         full_word
     }
+
+    /// Returns the number of characters in the `prefix`.
+    ///
+    /// Lengths are counted in characters rather than bytes, so every letter of an answer
+    /// occupies exactly one cell on the grid regardless of its UTF-8 encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::word::Segment;
+    ///
+    /// let segment = Segment::new("ÉT", 'É', "").unwrap();
+    /// assert_eq!(segment.prefix.len(), 3);
+    /// assert_eq!(segment.prefix_len(), 2);
+    /// ```
+    pub fn prefix_len(&self) -> usize {
+        self.prefix.chars().count()
+    }
+
+    /// Returns the number of characters in the `suffix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::word::Segment;
+    ///
+    /// let segment = Segment::new("", 'G', "ÜN").unwrap();
+    /// assert_eq!(segment.suffix_len(), 2);
+    /// ```
+    pub fn suffix_len(&self) -> usize {
+        self.suffix.chars().count()
+    }
+
+    /// Returns the number of characters in the full word, which is also the number of cells
+    /// it occupies on the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::word::Segment;
+    ///
+    /// let segment = Segment::new("İ", 'Ç', "İN").unwrap();
+    /// assert_eq!(segment.len(), 4);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.prefix_len() + 1 + self.suffix_len()
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -174,6 +278,20 @@ impl Serialize for Segment<'_> {
     }
 }
 
+/// Deserializes a `Segment` from the full word, crossing at its first character.
+/// The crossing point is not part of the serialized form; `Grid` deserialization
+/// moves it to a cell shared with another word.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Segment<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let word = <&'de str>::deserialize(deserializer)?;
+        Segment::split_at(word, 0).map_err(serde::de::Error::custom)
+    }
+}
+
 /// `Word` represents a word intended for placement in the crossword puzzle.
 /// It encapsulates the word's content (`Segment`), its `Position` on the grid,
 /// its calculated `origin` (start of the word), and its `Direction`.
@@ -188,6 +306,10 @@ pub struct Word<'a> {
     pub position: Position,
     /// The clue associated with this word.
     pub clue: &'a str,
+    /// The answer as it is shown in answer lists, such as `ICE CREAM` for `ICECREAM`, or
+    /// an empty string to show the answer itself.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "str::is_empty"))]
+    pub display: &'a str,
     /// The calculated origin of the word based on its position and direction.
     #[cfg_attr(feature = "serde", serde(rename = "position"))]
     pub origin: Position,
@@ -196,6 +318,65 @@ pub struct Word<'a> {
     pub direction: Direction,
 }
 
+/// The serialized form of a `Word`, mirroring the field names used by its `Serialize` impl.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+pub(crate) struct WordRepr<'a> {
+    /// The full answer.
+    pub(crate) answer: &'a str,
+    /// The clue of the word.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) clue: &'a str,
+    /// The display form of the answer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) display: &'a str,
+    /// The origin of the word.
+    pub(crate) position: Position,
+    /// The direction of the word.
+    pub(crate) orientation: Direction,
+}
+
+impl<'a> WordRepr<'a> {
+    /// Builds the `Word`, splitting the answer at the character index `crossing`.
+    pub(crate) fn into_word(self, crossing: usize) -> Result<Word<'a>, WordError> {
+        let position = match self.orientation {
+            Direction::Horizontal => Position {
+                x: self.position.x + crossing,
+                y: self.position.y,
+            },
+            Direction::Vertical => Position {
+                x: self.position.x,
+                y: self.position.y + crossing,
+            },
+            Direction::NotSet => self.position,
+        };
+        let mut word = Word {
+            segment: Segment::split_at(self.answer, crossing)?,
+            clue: self.clue,
+            display: self.display,
+            position,
+            direction: self.orientation,
+            ..Default::default()
+        };
+        word.update_position();
+        Ok(word)
+    }
+}
+
+/// Deserializes a `Word` from its `answer`, `clue`, `position` (the origin of the word)
+/// and `orientation`. The word crosses at its first character, at its origin.
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Word<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        WordRepr::deserialize(deserializer)?
+            .into_word(0)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 impl<'a> Word<'a> {
     /// Creates a new `Word` instance from its constituent parts.
     ///
@@ -308,10 +489,36 @@ impl<'a> Word<'a> {
         self
     }
 
+    /// Sets the form in which the word is shown in answer lists.
+    ///
+    /// This is a builder-pattern method, returning `self` for chaining.
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - The answer as it should be shown, such as `ICE CREAM` for `ICECREAM`.
+    ///
+    /// # Returns
+    ///
+    /// The `Word` instance with its `display` updated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::word::Word;
+    ///
+    /// let word = Word::value("ICE", 'C', "REAM").unwrap().display("ICE CREAM");
+    /// assert_eq!(word.display, "ICE CREAM");
+    /// ```
+    pub fn display(mut self, display: &'a str) -> Self {
+        self.display = display;
+        self
+    }
+
     /// Updates the `origin` of the word based on its `position`, `direction`, and `prefix` length.
     ///
     /// The `origin` represents the `Position` of the very first character of the word on the grid.
-    /// This is crucial for correctly placing the word on the `Grid`.
+    /// This is crucial for correctly placing the word on the `Grid`. The prefix length is counted
+    /// in characters, so multi-byte letters such as `É` or `İ` occupy a single cell.
     ///
     /// # Examples
     ///
@@ -322,15 +529,20 @@ impl<'a> Word<'a> {
     /// word.update_position();
     /// // 'L' is at x=3, prefix "APP" has length 3, so origin.x should be 3 - 3 = 0
     /// assert_eq!(word.origin, Position { x: 0, y: 0 });
+    ///
+    /// // Multi-byte letters count as one cell each.
+    /// let mut word = Word::value("ÄR", 'G', "ER").unwrap().position(2, 0).direction(Direction::Horizontal);
+    /// word.update_position();
+    /// assert_eq!(word.origin, Position { x: 0, y: 0 });
     /// ```
     pub fn update_position(&mut self) {
         match self.direction {
             Direction::Vertical => {
                 self.origin.x = self.position.x;
-                self.origin.y = self.position.y.saturating_sub(self.segment.prefix.len())
+                self.origin.y = self.position.y.saturating_sub(self.segment.prefix_len())
             }
             Direction::Horizontal => {
-                self.origin.x = self.position.x.saturating_sub(self.segment.prefix.len());
+                self.origin.x = self.position.x.saturating_sub(self.segment.prefix_len());
                 self.origin.y = self.position.y;
             }
             _ => {}
@@ -358,9 +570,13 @@ impl<'a> Word<'a> {
     /// assert_eq!(positions[0], Position { x: 0, y: 0 });
     /// assert_eq!(positions[1], Position { x: 1, y: 0 });
     /// assert_eq!(positions[2], Position { x: 2, y: 0 });
+    ///
+    /// let mut word = Word::value("", 'Ö', "L").unwrap().position(0, 0).direction(Direction::Vertical);
+    /// word.update_position();
+    /// assert_eq!(word.positions(), vec![Position { x: 0, y: 0 }, Position { x: 0, y: 1 }]);
     /// ```
     pub fn positions(&self) -> Vec<Position> {
-        let length = self.segment.full_word().len();
+        let length = self.segment.len();
         match self.direction {
             Direction::Horizontal => (self.origin.x..self.origin.x + length)
                 .map(|x| Position {
@@ -404,4 +620,34 @@ impl<'a> Word<'a> {
     pub fn to_json_pretty(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(&self)
     }
+
+    /// Deserializes a `Word` from a JSON string produced by `to_json`.
+    ///
+    /// The answer and clue borrow from `json`, so they must not contain JSON escape
+    /// sequences. The word crosses at its first character.
+    ///
+    /// This function requires the `serde` feature to be enabled.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Word)` if `json` describes a valid word.
+    /// - `Err(serde_json::Error)` if deserialization fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crossword_puzzle::word::{Word, Direction, Position};
+    ///
+    /// let json = r#"{"answer":"RUST","clue":"Iron oxide","position":{"x":2,"y":1},"orientation":"vertical"}"#;
+    /// let word = Word::from_json(json).unwrap();
+    /// assert_eq!(word.segment.full_word_str(), "RUST");
+    /// assert_eq!(word.clue, "Iron oxide");
+    /// assert_eq!(word.origin, Position { x: 2, y: 1 });
+    /// assert_eq!(word.direction, Direction::Vertical);
+    /// assert_eq!(word.to_json().unwrap(), json);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &'a str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
 }